@@ -0,0 +1,214 @@
+//! Day 1: Calorie Counting.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Something wrong with the inventory, with the 1-based input line it is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    NotANumber { line: usize, text: String },
+    EmptyElf { line: usize, elf: usize },
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::NotANumber { line, text } => write!(f, "line {line}: {text:?} is not a number"),
+            Issue::EmptyElf { line, elf } => write!(f, "line {line}: elf {elf} carries nothing"),
+        }
+    }
+}
+
+/// Calories carried by each elf, in input order.
+///
+/// Malformed lines are skipped and recorded in `issues` rather than failing
+/// the whole inventory, so elf indices always match the blocks of the input.
+#[derive(Debug)]
+pub struct Inventory {
+    pub elves: Vec<Vec<i32>>,
+    pub issues: Vec<Issue>,
+}
+
+impl FromStr for Inventory {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = vec![];
+        let mut issues = vec![];
+        let mut elf = vec![];
+        let mut start = 1;
+
+        let mut close = |elf: &mut Vec<i32>, start: usize, issues: &mut Vec<Issue>| {
+            if elf.is_empty() {
+                issues.push(Issue::EmptyElf {
+                    line: start,
+                    elf: elves.len(),
+                });
+            }
+            elves.push(std::mem::take(elf));
+        };
+
+        let mut lines = 0;
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            lines = line_number;
+
+            if line.trim().is_empty() {
+                close(&mut elf, start, &mut issues);
+                start = line_number + 1;
+                continue;
+            }
+
+            match line.trim().parse() {
+                Ok(calories) => elf.push(calories),
+                Err(_) => issues.push(Issue::NotANumber {
+                    line: line_number,
+                    text: line.to_string(),
+                }),
+            }
+        }
+
+        if start <= lines {
+            close(&mut elf, start, &mut issues);
+        }
+
+        Ok(Self { elves, issues })
+    }
+}
+
+/// Distribution of the calories carried per elf.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub p25: i32,
+    pub p75: i32,
+    pub p90: i32,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} elves, mean {:.1}, median {:.1}, p25 {}, p75 {}, p90 {}",
+            self.elves, self.mean, self.median, self.p25, self.p75, self.p90
+        )
+    }
+}
+
+impl Inventory {
+    /// Total calories per elf, in input order.
+    pub fn totals(&self) -> Vec<i32> {
+        self.elves.iter().map(|e| e.iter().sum()).collect()
+    }
+
+    /// The `n` best-stocked elves as `(index, total)`, largest first; ties go
+    /// to the elf that comes first. Only `n` elves are kept in memory at once.
+    pub fn top(&self, n: usize) -> Vec<(usize, i32)> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut heap = BinaryHeap::with_capacity(n + 1);
+        for (i, total) in self.elves.iter().map(|e| e.iter().sum::<i32>()).enumerate() {
+            heap.push(Reverse((total, Reverse(i))));
+            if heap.len() > n {
+                heap.pop();
+            }
+        }
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(i)))| (i, total))
+            .collect()
+    }
+
+    /// Nearest-rank percentile of the totals, for `p` in `0.0..=100.0`.
+    pub fn percentile(&self, p: f64) -> Option<i32> {
+        let mut totals = self.totals();
+        totals.sort_unstable();
+        nearest_rank(&totals, p)
+    }
+
+    /// Mean, median and a few percentiles of the totals, if there are elves.
+    pub fn stats(&self) -> Option<Stats> {
+        let mut totals = self.totals();
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort_unstable();
+
+        let len = totals.len();
+        let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / len as f64;
+        let median = if len.is_multiple_of(2) {
+            (totals[len / 2 - 1] as f64 + totals[len / 2] as f64) / 2.0
+        } else {
+            totals[len / 2] as f64
+        };
+
+        Some(Stats {
+            elves: len,
+            mean,
+            median,
+            p25: nearest_rank(&totals, 25.0)?,
+            p75: nearest_rank(&totals, 75.0)?,
+            p90: nearest_rank(&totals, 90.0)?,
+        })
+    }
+}
+
+fn nearest_rank(sorted: &[i32], p: f64) -> Option<i32> {
+    if sorted.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.saturating_sub(1)])
+}
+
+/// Calories carried by the best-stocked elf, 0 when there are no elves.
+pub fn part1(inventory: &Inventory) -> i32 {
+    inventory.top(1).first().map_or(0, |&(_, total)| total)
+}
+
+/// Calories carried by the three best-stocked elves together.
+pub fn part2(inventory: &Inventory) -> i32 {
+    inventory.top(3).iter().map(|&(_, total)| total).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_blocks_are_flagged() {
+        let inventory: Inventory = "100\n200\n\n\nabc\n\n300\n".parse().unwrap();
+
+        assert_eq!(
+            inventory.elves,
+            vec![vec![100, 200], vec![], vec![], vec![300]]
+        );
+        assert_eq!(
+            inventory.issues,
+            vec![
+                Issue::EmptyElf { line: 4, elf: 1 },
+                Issue::NotANumber {
+                    line: 5,
+                    text: "abc".into()
+                },
+                Issue::EmptyElf { line: 5, elf: 2 },
+            ]
+        );
+        assert_eq!(inventory.top(2), vec![(0, 300), (3, 300)]);
+
+        let empty: Inventory = "".parse().unwrap();
+        assert_eq!(part1(&empty), 0);
+        assert_eq!(part2(&empty), 0);
+    }
+}
@@ -1,31 +1,26 @@
-use std::io::{self, Read};
+use aoc::runner::Runner;
+use day01::Inventory;
+use std::env;
 
 fn main() {
-    let mut input = String::new();
-    io::stdin()
-        .lock()
-        .read_to_string(&mut input)
-        .expect("Couldn't read stdin");
+    let runner = Runner::new(2022, 1);
+    let inventory: Inventory = aoc::read_stdin().parse().expect("Couldn't parse inventory");
+    let top = env::args()
+        .nth(1)
+        .map(|n| n.parse().expect("Usage: day01 [top elves]"))
+        .unwrap_or(3);
 
-    let mut sums: Vec<i32> = input
-        .split("\n\n")
-        .map(|s| {
-            s.split('\n')
-                .filter(|s| !s.is_empty())
-                .map(|s| s.parse::<i32>().unwrap())
-                .sum::<i32>()
-        })
-        .collect();
+    for issue in &inventory.issues {
+        eprintln!("Malformed inventory, {issue}");
+    }
 
-    sums.sort_unstable();
+    runner.part(1, "Top 1 most calories", || day01::part1(&inventory));
+    runner.part(2, "Top 3 most calories sum", || day01::part2(&inventory));
 
-    println!(
-        "Top 1 most calories: {}",
-        sums.iter().max().expect("Couldn't find max")
-    );
-
-    println!(
-        "Top 3 most calories sum: {}",
-        sums.iter().rev().take(3).sum::<i32>()
-    );
+    for (rank, (elf, total)) in inventory.top(top).into_iter().enumerate() {
+        println!("#{}: elf {} with {} calories", rank + 1, elf, total);
+    }
+    if let Some(stats) = inventory.stats() {
+        println!("Distribution: {stats}");
+    }
 }
@@ -0,0 +1,350 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! The rules work for any cyclic dominance game with an odd number of shapes,
+//! such as Rock-Paper-Scissors-Lizard-Spock: every shape beats the half of
+//! the other shapes that come just before it in the cycle.
+
+use std::str::FromStr;
+
+/// A shape, as its position in the cycle of the game's rules.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Shape(pub usize);
+
+/// The opponent's shape and mine.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Hand(pub Shape, pub Shape);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Victory,
+    Draw,
+    Loss,
+}
+
+/// How to read the second column of the strategy guide.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Reading {
+    /// The letter is the shape to play.
+    Shape,
+    /// The letter is how much to win or lose by: the middle letter draws,
+    /// the ones after it beat the opponent and the ones before it lose.
+    Outcome,
+}
+
+/// Shapes of a game, the letters naming them and how rounds are scored.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub shapes: Vec<String>,
+    pub opponent_letters: Vec<char>,
+    pub my_letters: Vec<char>,
+    pub shape_points: Vec<i32>,
+    pub loss_points: i32,
+    pub draw_points: i32,
+    pub victory_points: i32,
+}
+
+impl Rules {
+    /// A game over `shapes`, listed so that each one beats the shapes just
+    /// before it. Letters start at `A` and `X` (going back from `X` when there
+    /// are more than three shapes), and shapes are worth 1, 2, 3...
+    pub fn new(shapes: &[&str]) -> Result<Self, String> {
+        let n = shapes.len();
+        if !(3..=25).contains(&n) || n.is_multiple_of(2) {
+            return Err(format!(
+                "A cyclic game needs an odd number of shapes up to 25, got {n}"
+            ));
+        }
+
+        let first_mine = b'X' - (n as u8 - 3);
+        Ok(Self {
+            shapes: shapes.iter().map(|s| s.to_string()).collect(),
+            opponent_letters: (b'A'..).take(n).map(char::from).collect(),
+            my_letters: (first_mine..).take(n).map(char::from).collect(),
+            shape_points: (1..).take(n).collect(),
+            loss_points: 0,
+            draw_points: 3,
+            victory_points: 6,
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap()
+    }
+
+    pub fn with_letters(mut self, opponent: &str, mine: &str) -> Result<Self, String> {
+        self.opponent_letters = self.letters(opponent)?;
+        self.my_letters = self.letters(mine)?;
+        Ok(self)
+    }
+
+    pub fn with_shape_points(mut self, points: &[i32]) -> Result<Self, String> {
+        if points.len() != self.shapes.len() {
+            return Err(format!(
+                "Expected {} shape points, got {}",
+                self.shapes.len(),
+                points.len()
+            ));
+        }
+
+        self.shape_points = points.to_vec();
+        Ok(self)
+    }
+
+    pub fn with_outcome_points(mut self, loss: i32, draw: i32, victory: i32) -> Self {
+        self.loss_points = loss;
+        self.draw_points = draw;
+        self.victory_points = victory;
+        self
+    }
+
+    fn letters(&self, letters: &str) -> Result<Vec<char>, String> {
+        let letters: Vec<char> = letters.chars().collect();
+        let distinct = letters
+            .iter()
+            .enumerate()
+            .all(|(i, l)| !letters[..i].contains(l));
+
+        if letters.len() != self.shapes.len() || !distinct {
+            return Err(format!(
+                "Expected {} distinct letters, got {:?}",
+                self.shapes.len(),
+                letters.iter().collect::<String>()
+            ));
+        }
+
+        Ok(letters)
+    }
+
+    /// How the round goes for me.
+    pub fn outcome(&self, hand: &Hand) -> Outcome {
+        let n = self.shapes.len();
+        match (hand.1 .0 + n - hand.0 .0) % n {
+            0 => Outcome::Draw,
+            d if d <= n / 2 => Outcome::Victory,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The hand played when the second column holds the `column`-th letter.
+    pub fn hand(&self, opponent: Shape, column: usize, reading: Reading) -> Hand {
+        let n = self.shapes.len();
+        let mine = match reading {
+            Reading::Shape => column,
+            Reading::Outcome => (opponent.0 + n + column - n / 2) % n,
+        };
+
+        Hand(opponent, Shape(mine))
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0]
+    }
+}
+
+/// Outcome points plus the points for my shape.
+pub fn score(rules: &Rules, hand: &Hand) -> i32 {
+    let outcome = match rules.outcome(hand) {
+        Outcome::Victory => rules.victory_points,
+        Outcome::Draw => rules.draw_points,
+        Outcome::Loss => rules.loss_points,
+    };
+
+    outcome + rules.shape_points[hand.1 .0]
+}
+
+/// The encrypted strategy guide: the opponent's shape and the index of the
+/// second column's letter among the rules' letters, for every round.
+#[derive(Debug)]
+pub struct StrategyGuide {
+    pub rounds: Vec<(Shape, usize)>,
+}
+
+impl StrategyGuide {
+    pub fn parse(s: &str, rules: &Rules) -> Result<Self, String> {
+        let position = |letters: &[char], c| letters.iter().position(|&l| l == c);
+
+        let rounds = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let mut columns = l.split_whitespace();
+                let letter = |c: Option<&str>| {
+                    let mut chars = c?.chars();
+                    chars.next().filter(|_| chars.next().is_none())
+                };
+
+                match (
+                    letter(columns.next()),
+                    letter(columns.next()),
+                    columns.next(),
+                ) {
+                    (Some(om), Some(mm), None) => position(&rules.opponent_letters, om)
+                        .zip(position(&rules.my_letters, mm))
+                        .map(|(om, mm)| (Shape(om), mm)),
+                    _ => None,
+                }
+                .ok_or_else(|| format!("Invalid strategy on line {}: {l:?}", i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { rounds })
+    }
+
+    pub fn hands(&self, rules: &Rules, reading: Reading) -> Vec<Hand> {
+        self.rounds
+            .iter()
+            .map(|&(opponent, column)| rules.hand(opponent, column, reading))
+            .collect()
+    }
+}
+
+impl FromStr for StrategyGuide {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, &Rules::rock_paper_scissors())
+    }
+}
+
+/// Total score of the guide under a reading of its second column.
+pub fn total(guide: &StrategyGuide, rules: &Rules, reading: Reading) -> i32 {
+    guide
+        .hands(rules, reading)
+        .iter()
+        .map(|hand| score(rules, hand))
+        .sum()
+}
+
+/// Total score when column two is the shape to play.
+pub fn part1(guide: &StrategyGuide, rules: &Rules) -> i32 {
+    total(guide, rules, Reading::Shape)
+}
+
+/// Total score when column two is the outcome to reach.
+pub fn part2(guide: &StrategyGuide, rules: &Rules) -> i32 {
+    total(guide, rules, Reading::Outcome)
+}
+
+/// One way of reading the second column: which meaning each letter takes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub reading: Reading,
+    /// For each of the rules' second-column letters, the column index it
+    /// stands for under `reading` (the identity is the puzzle's mapping).
+    pub meanings: Vec<usize>,
+    pub total: i32,
+}
+
+impl Mapping {
+    pub fn describe(&self, rules: &Rules) -> String {
+        let n = rules.shapes.len() as isize;
+        let meanings: Vec<String> = rules
+            .my_letters
+            .iter()
+            .zip(&self.meanings)
+            .map(|(letter, &meaning)| {
+                let meaning = match self.reading {
+                    Reading::Shape => rules.shapes[meaning].clone(),
+                    Reading::Outcome => match meaning as isize - n / 2 {
+                        0 => "Draw".to_string(),
+                        d if d > 0 => format!("Win by {d}"),
+                        d => format!("Lose by {}", -d),
+                    },
+                };
+                format!("{letter}={meaning}")
+            })
+            .collect();
+
+        format!("{:?}: {}", self.reading, meanings.join(", "))
+    }
+}
+
+/// Every permutation of `0..n`, by Heap's algorithm.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    let mut items: Vec<usize> = (0..n).collect();
+    let mut counters = vec![0; n];
+    let mut all = vec![items.clone()];
+
+    let mut i = 1;
+    while i < n {
+        if counters[i] < i {
+            let j = if i.is_multiple_of(2) { 0 } else { counters[i] };
+            items.swap(j, i);
+            all.push(items.clone());
+            counters[i] += 1;
+            i = 1;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+
+    all
+}
+
+/// The total score of the guide for every assignment of meanings to the
+/// second column's letters, under both readings.
+///
+/// There are `n!` assignments per reading for a game of `n` shapes, so this
+/// is only practical for small games. Rounds are first counted per letter
+/// pair, which keeps each assignment at `n * n` hands however long the
+/// guide is.
+pub fn mappings(guide: &StrategyGuide, rules: &Rules) -> Vec<Mapping> {
+    let n = rules.shapes.len();
+    let mut counts = vec![vec![0; n]; n];
+    for &(opponent, column) in &guide.rounds {
+        counts[opponent.0][column] += 1;
+    }
+
+    let permutations = permutations(n);
+    [Reading::Shape, Reading::Outcome]
+        .into_iter()
+        .flat_map(|reading| {
+            let counts = &counts;
+            permutations.iter().map(move |meanings| {
+                let total = (0..n)
+                    .flat_map(|opponent| (0..n).map(move |column| (opponent, column)))
+                    .map(|(opponent, column)| {
+                        let hand = rules.hand(Shape(opponent), meanings[column], reading);
+                        counts[opponent][column] * score(rules, &hand)
+                    })
+                    .sum();
+
+                Mapping {
+                    reading,
+                    meanings: meanings.clone(),
+                    total,
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let guide = StrategyGuide::parse("A V\nE Z\nC X\nB W\n", &rules).unwrap();
+
+        assert_eq!(part1(&guide, &rules), 23);
+        assert_eq!(part2(&guide, &rules), 19);
+    }
+
+    #[test]
+    fn mappings_include_the_puzzle_readings() {
+        let rules = Rules::rock_paper_scissors();
+        let guide: StrategyGuide = "A Y\nB X\nC Z\n".parse().unwrap();
+        let mappings = mappings(&guide, &rules);
+
+        assert_eq!(mappings.len(), 12);
+        assert_eq!(mappings[0].total, 15);
+        assert_eq!(mappings[6].total, 12);
+    }
+}
@@ -1,114 +1,81 @@
-use std::io::{self, BufRead};
+use aoc::runner::Runner;
+use day02::{Rules, StrategyGuide};
+use std::{env, process};
 
-#[derive(Copy, Clone, PartialEq)]
-enum Shape {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
+fn usage() -> ! {
+    eprintln!(
+        "Usage: day02 [--game rps|rpsls] [--letters <opponent> <mine>] [--points <p1,p2,...>] [--outcomes <loss,draw,victory>] [--mappings]"
+    );
+    process::exit(1);
 }
 
-struct OpponentMove(char);
-struct MyMove(char);
-struct Hand(Shape, Shape);
-struct StrategicHand(Shape, Outcome);
-
-impl From<OpponentMove> for Shape {
-    fn from(m: OpponentMove) -> Self {
-        match m.0 {
-            'A' => Shape::Rock,
-            'B' => Shape::Paper,
-            'C' => Shape::Scissors,
-            _ => unreachable!(),
-        }
-    }
-}
-
-impl From<MyMove> for Shape {
-    fn from(m: MyMove) -> Self {
-        match m.0 {
-            'X' => Shape::Rock,
-            'Y' => Shape::Paper,
-            'Z' => Shape::Scissors,
-            _ => unreachable!(),
-        }
-    }
+fn numbers(s: &str) -> Vec<i32> {
+    s.split(',')
+        .map(|n| n.trim().parse().unwrap_or_else(|_| usage()))
+        .collect()
 }
 
-#[derive(PartialEq)]
-enum Outcome {
-    Victory = 6,
-    Draw = 3,
-    Loss = 0,
-}
-
-impl From<Shape> for Outcome {
-    fn from(s: Shape) -> Self {
-        match s {
-            Shape::Rock => Outcome::Loss,
-            Shape::Paper => Outcome::Draw,
-            Shape::Scissors => Outcome::Victory,
-        }
-    }
-}
+fn options() -> Result<(Rules, bool), String> {
+    let mut rules = Rules::rock_paper_scissors();
+    let mut show_mappings = false;
 
-impl From<&Hand> for Outcome {
-    fn from(hand: &Hand) -> Self {
-        if hand.0 == hand.1 {
-            Outcome::Draw
-        } else {
-            match (hand.0, hand.1) {
-                (Shape::Rock, Shape::Paper) => Outcome::Victory,
-                (Shape::Rock, Shape::Scissors) => Outcome::Loss,
-                (Shape::Paper, Shape::Rock) => Outcome::Loss,
-                (Shape::Paper, Shape::Scissors) => Outcome::Victory,
-                (Shape::Scissors, Shape::Rock) => Outcome::Victory,
-                (Shape::Scissors, Shape::Paper) => Outcome::Loss,
-                _ => unreachable!(),
-            }
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        if arg == "--mappings" {
+            show_mappings = true;
+            continue;
         }
-    }
-}
 
-impl From<StrategicHand> for Hand {
-    fn from(hand: StrategicHand) -> Self {
-        if hand.1 == Outcome::Draw {
-            Hand(hand.0, hand.0)
-        } else {
-            match (hand.0, hand.1) {
-                (Shape::Rock, Outcome::Victory) => Hand(Shape::Rock, Shape::Paper),
-                (Shape::Rock, Outcome::Loss) => Hand(Shape::Rock, Shape::Scissors),
-                (Shape::Paper, Outcome::Victory) => Hand(Shape::Paper, Shape::Scissors),
-                (Shape::Paper, Outcome::Loss) => Hand(Shape::Paper, Shape::Rock),
-                (Shape::Scissors, Outcome::Victory) => Hand(Shape::Scissors, Shape::Rock),
-                (Shape::Scissors, Outcome::Loss) => Hand(Shape::Scissors, Shape::Paper),
-                _ => unreachable!(),
+        rules = match (arg, args.next()) {
+            ("--game", Some("rps")) => Rules::rock_paper_scissors(),
+            ("--game", Some("rpsls")) => Rules::rock_paper_scissors_lizard_spock(),
+            ("--letters", Some(opponent)) => {
+                rules.with_letters(opponent, args.next().unwrap_or_else(|| usage()))?
             }
-        }
+            ("--points", Some(points)) => rules.with_shape_points(&numbers(points))?,
+            ("--outcomes", Some(points)) => match numbers(points)[..] {
+                [loss, draw, victory] => rules.with_outcome_points(loss, draw, victory),
+                _ => usage(),
+            },
+            _ => usage(),
+        };
     }
-}
 
-fn score(hand: &Hand) -> i32 {
-    Outcome::from(hand) as i32 + hand.1 as i32
+    Ok((rules, show_mappings))
 }
 
 fn main() {
-    let hands: Vec<Hand> = io::stdin()
-        .lock()
-        .lines()
-        .map(|l| l.unwrap())
-        .map(|l| (l.chars().next().unwrap(), l.chars().nth(2).unwrap()))
-        .map(|(om, mm)| (OpponentMove(om), MyMove(mm)))
-        .map(|(om, mm)| Hand(om.into(), mm.into()))
-        .collect();
+    let runner = Runner::new(2022, 2);
+    let (rules, show_mappings) = options().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    let guide =
+        StrategyGuide::parse(&aoc::read_stdin(), &rules).expect("Couldn't parse strategy guide");
 
-    let step1_score: i32 = hands.iter().map(score).sum();
+    runner.part(1, "Step 1", || day02::part1(&guide, &rules));
+    runner.part(2, "Step 2", || day02::part2(&guide, &rules));
 
-    let step2_score: i32 = hands
-        .iter()
-        .map(|hand| StrategicHand(hand.0, hand.1.into()))
-        .map(|hand| score(&hand.into()))
-        .sum();
+    if show_mappings {
+        let mut mappings = day02::mappings(&guide, &rules);
+        mappings.sort_by_key(|m| -m.total);
 
-    println!("Step 1: {}", step1_score);
-    println!("Step 2: {}", step2_score);
+        println!("Every reading of the second column:");
+        for mapping in &mappings {
+            println!("{:>8}  {}", mapping.total, mapping.describe(&rules));
+        }
+        if let (Some(best), Some(worst)) = (mappings.first(), mappings.last()) {
+            println!(
+                "Best mapping: {} scores {}",
+                best.describe(&rules),
+                best.total
+            );
+            println!(
+                "Worst mapping: {} scores {}",
+                worst.describe(&rules),
+                worst.total
+            );
+        }
+    }
 }
@@ -0,0 +1,292 @@
+//! Day 3: Rucksack Reorganization.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Item priority: `a`-`z` are 1-26 and `A`-`Z` are 27-52.
+pub fn priority(c: char) -> i32 {
+    let ascii: i32 = c as i32;
+
+    if ascii >= 'a' as i32 {
+        ascii - 'a' as i32 + 1
+    } else {
+        ascii - 'A' as i32 + 27
+    }
+}
+
+/// The item of a given priority, the inverse of `priority`.
+pub fn item(priority: i32) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        _ => (b'A' + priority as u8 - 27) as char,
+    }
+}
+
+/// A set of item types, with the bit of each item's priority set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct ItemSet(pub u64);
+
+impl ItemSet {
+    /// Every item, so that it is the identity of `intersection`.
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    pub fn intersection(self, other: Self) -> Self {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Sum of the priorities of the items in the set.
+    pub fn priorities(&self) -> i32 {
+        self.items().map(priority).sum()
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = char> + '_ {
+        (1..=52).filter(|p| self.0 & (1 << p) != 0).map(item)
+    }
+}
+
+impl From<&str> for ItemSet {
+    fn from(items: &str) -> Self {
+        ItemSet(items.chars().fold(0, |set, c| set | 1 << priority(c)))
+    }
+}
+
+impl FromIterator<ItemSet> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = ItemSet>>(sets: I) -> Self {
+        sets.into_iter().fold(ItemSet::ALL, ItemSet::intersection)
+    }
+}
+
+/// How rucksacks are split in compartments and elves in groups.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub compartments: usize,
+    pub group_size: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            compartments: 2,
+            group_size: 3,
+        }
+    }
+}
+
+/// One rucksack's contents per line, checked against a layout.
+#[derive(Debug)]
+pub struct Rucksacks {
+    pub lines: Vec<String>,
+    pub layout: Layout,
+}
+
+impl Rucksacks {
+    pub fn parse(s: &str, layout: Layout) -> Result<Self, String> {
+        if layout.compartments == 0 || layout.group_size == 0 {
+            return Err("Compartment count and group size must be positive".into());
+        }
+
+        let lines: Vec<String> = s.lines().map(String::from).collect();
+
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(c) = line.chars().find(|c| !c.is_ascii_alphabetic()) {
+                return Err(format!("Line {}: {c:?} is not an item", i + 1));
+            }
+            if !line.len().is_multiple_of(layout.compartments) {
+                return Err(format!(
+                    "Line {}: {} items don't split evenly in {} compartments",
+                    i + 1,
+                    line.len(),
+                    layout.compartments
+                ));
+            }
+        }
+
+        if !lines.len().is_multiple_of(layout.group_size) {
+            return Err(format!(
+                "{} rucksacks don't split evenly in groups of {}",
+                lines.len(),
+                layout.group_size
+            ));
+        }
+
+        Ok(Self { lines, layout })
+    }
+
+    /// Items found in every compartment, per rucksack.
+    pub fn misplaced(&self) -> Vec<Shared> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let size = line.len() / self.layout.compartments;
+                let items = (0..self.layout.compartments)
+                    .map(|c| ItemSet::from(&line[c * size..(c + 1) * size]))
+                    .collect();
+
+                Shared {
+                    lines: (i + 1, i + 1),
+                    items,
+                }
+            })
+            .collect()
+    }
+
+    /// Items found in every rucksack of a group, per group.
+    pub fn badges(&self) -> Vec<Shared> {
+        self.lines
+            .chunks(self.layout.group_size)
+            .enumerate()
+            .map(|(i, group)| {
+                let first = i * self.layout.group_size + 1;
+
+                Shared {
+                    lines: (first, first + group.len() - 1),
+                    items: group.iter().map(|l| ItemSet::from(l.as_str())).collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Rucksacks {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Layout::default())
+    }
+}
+
+/// Items shared by a rucksack's compartments or by a group, along with the
+/// 1-based lines they come from. The puzzle expects exactly one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Shared {
+    pub lines: (usize, usize),
+    pub items: ItemSet,
+}
+
+impl Shared {
+    pub fn is_anomaly(&self) -> bool {
+        self.items.len() != 1
+    }
+}
+
+impl Display for Shared {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.lines {
+            (first, last) if first == last => write!(f, "line {first}: ")?,
+            (first, last) => write!(f, "lines {first}-{last}: ")?,
+        }
+
+        if self.items.is_empty() {
+            f.write_str("no shared item")
+        } else {
+            write!(
+                f,
+                "{} shared items ({})",
+                self.items.len(),
+                self.items.items().collect::<String>()
+            )
+        }
+    }
+}
+
+/// Sum of the priorities of the misplaced items; a rucksack sharing several
+/// items between its compartments counts all of them.
+pub fn part1(rucksacks: &Rucksacks) -> i32 {
+    rucksacks
+        .misplaced()
+        .iter()
+        .map(|s| s.items.priorities())
+        .sum()
+}
+
+/// Sum of the priorities of each group's badge.
+pub fn part2(rucksacks: &Rucksacks) -> i32 {
+    rucksacks
+        .badges()
+        .iter()
+        .map(|s| s.items.priorities())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities_and_items() {
+        assert_eq!(['a', 'z', 'A', 'Z'].map(priority), [1, 26, 27, 52]);
+        assert!((1..=52).all(|p| priority(item(p)) == p));
+    }
+
+    #[test]
+    fn set_intersection() {
+        let shared = ItemSet::from("abcZ").intersection(ItemSet::from("bcdZ"));
+        assert_eq!(shared.items().collect::<String>(), "bcZ");
+        assert_eq!(shared.priorities(), 2 + 3 + 52);
+
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert_eq!(
+            ItemSet::from("xy").intersection(ItemSet::ALL),
+            ItemSet::from("xy")
+        );
+        assert_eq!(std::iter::empty().collect::<ItemSet>(), ItemSet::ALL);
+        assert!(ItemSet::from("ab")
+            .intersection(ItemSet::from("cd"))
+            .is_empty());
+    }
+
+    #[test]
+    fn compartments_and_groups() {
+        let rucksacks: Rucksacks = include_str!("../input_small.txt").parse().unwrap();
+        assert_eq!(part1(&rucksacks), 157);
+        assert_eq!(part2(&rucksacks), 70);
+        assert!(rucksacks.misplaced().iter().all(|s| !s.is_anomaly()));
+
+        let layout = Layout {
+            compartments: 3,
+            group_size: 2,
+        };
+        let rucksacks = Rucksacks::parse("abcaxyazz\nqqqqqqqqq\nbxbybz\nbbbbbb", layout).unwrap();
+        let misplaced = rucksacks.misplaced();
+        assert_eq!(misplaced[0].to_string(), "line 1: 1 shared items (a)");
+        assert_eq!(misplaced[2].to_string(), "line 3: 1 shared items (b)");
+        assert_eq!(part1(&rucksacks), 1 + 17 + 2 + 2);
+
+        let badges = rucksacks.badges();
+        assert_eq!(badges[0].to_string(), "lines 1-2: no shared item");
+        assert!(badges[0].is_anomaly());
+        assert_eq!(badges[1].lines, (3, 4));
+        assert_eq!(part2(&rucksacks), 2);
+    }
+
+    #[test]
+    fn layout_errors() {
+        let layout = Layout {
+            compartments: 3,
+            group_size: 2,
+        };
+        assert_eq!(
+            Rucksacks::parse("abc\nab", layout).unwrap_err(),
+            "Line 2: 2 items don't split evenly in 3 compartments"
+        );
+        assert_eq!(
+            Rucksacks::parse("abc", layout).unwrap_err(),
+            "1 rucksacks don't split evenly in groups of 2"
+        );
+        assert_eq!(
+            "ab1c".parse::<Rucksacks>().unwrap_err(),
+            "Line 1: '1' is not an item"
+        );
+    }
+}
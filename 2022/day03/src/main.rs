@@ -1,59 +1,44 @@
-use std::io::{self, BufRead};
+use aoc::runner::Runner;
+use day03::{Layout, Rucksacks};
+use std::{env, process};
 
-fn priority(c: char) -> i32 {
-    let ascii: i32 = c as i32;
-
-    if ascii >= 'a' as i32 {
-        ascii - 'a' as i32 + 1
-    } else {
-        ascii - 'A' as i32 + 27
-    }
-}
-
-fn find_common(group: &[String]) -> i32 {
-    group
-        .iter()
-        .map(|l| split_halves(l))
-        .map(|(left, right)| {
-            for c in left.chars() {
-                if right.contains(c) {
-                    return priority(c);
-                }
-            }
-            unreachable!()
-        })
-        .sum()
+fn usage() -> ! {
+    eprintln!("Usage: day03 [--compartments <count>] [--group <size>]");
+    process::exit(1);
 }
 
-fn find_badge(group: &[String]) -> char {
-    for c in group[0].chars() {
-        if group[1].contains(c) && group[2].contains(c) {
-            return c;
+fn layout() -> Layout {
+    let mut layout = Layout::default();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--compartments" => layout.compartments = value,
+            "--group" => layout.group_size = value,
+            _ => usage(),
         }
     }
-    unreachable!()
-}
 
-fn split_halves(l: &str) -> (String, String) {
-    (
-        l[0..(l.len() / 2)].to_string(),
-        l[(l.len() / 2)..l.len()].to_string(),
-    )
+    layout
 }
 
 fn main() {
-    let lines: Vec<String> = io::stdin().lock().lines().map(|l| l.unwrap()).collect();
-
-    let (badge_priorities, item_priorities): (Vec<i32>, Vec<i32>) = lines
-        .chunks(3)
-        .map(|group| {
-            let badge_priority = priority(find_badge(group));
-            let item_priorities = find_common(group);
+    let runner = Runner::new(2022, 3);
+    let rucksacks =
+        Rucksacks::parse(&aoc::read_stdin(), layout()).expect("Couldn't parse rucksacks");
 
-            (badge_priority, item_priorities)
-        })
-        .unzip();
+    for shared in rucksacks.misplaced().iter().filter(|s| s.is_anomaly()) {
+        eprintln!("Compartments of {shared}");
+    }
+    for shared in rucksacks.badges().iter().filter(|s| s.is_anomaly()) {
+        eprintln!("Group on {shared}");
+    }
 
-    println!("Step 1: {:?}", item_priorities.iter().sum::<i32>());
-    println!("Step 2: {:?}", badge_priorities.iter().sum::<i32>());
+    runner.part(1, "Step 1", || day03::part1(&rucksacks));
+    runner.part(2, "Step 2", || day03::part2(&rucksacks));
 }
@@ -0,0 +1,261 @@
+//! Day 4: Camp Cleanup.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// An inclusive `start-end` range of section IDs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sections {
+    pub start: i32,
+    pub end: i32,
+}
+
+impl Sections {
+    pub fn len(&self) -> usize {
+        (self.end - self.start + 1) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    pub fn contains(&self, other: &Sections) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Sections) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl FromStr for Sections {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| format!("Expected start-end, got {s:?}"))?;
+        let bound = |b: &str| {
+            b.parse()
+                .map_err(|_| format!("Expected integer, got {b:?}"))
+        };
+        let sections = Sections {
+            start: bound(start)?,
+            end: bound(end)?,
+        };
+
+        if sections.is_empty() {
+            return Err(format!("Range {s:?} ends before it starts"));
+        }
+
+        Ok(sections)
+    }
+}
+
+impl Display for Sections {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+/// Consecutive runs of sections with how many ranges cover each run, in order
+/// and without gaps from the first start to the last end.
+pub fn coverage<'a>(ranges: impl IntoIterator<Item = &'a Sections>) -> Vec<(Sections, usize)> {
+    let mut events: Vec<(i32, isize)> = ranges
+        .into_iter()
+        .flat_map(|r| [(r.start, 1), (r.end + 1, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut runs = vec![];
+    let mut count = 0;
+    for pair in events.windows(2) {
+        let ((at, delta), (next, _)) = (pair[0], pair[1]);
+        count += delta;
+        if next > at {
+            runs.push((
+                Sections {
+                    start: at,
+                    end: next - 1,
+                },
+                count as usize,
+            ));
+        }
+    }
+
+    runs
+}
+
+/// The section ranges of the elves working together on one line.
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub elves: Vec<Sections>,
+}
+
+impl Group {
+    fn pairs(&self) -> impl Iterator<Item = (&Sections, &Sections)> {
+        self.elves
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| self.elves[i + 1..].iter().map(move |b| (a, b)))
+    }
+
+    /// Whether some elf's range fully contains another's.
+    pub fn has_containment(&self) -> bool {
+        self.pairs().any(|(a, b)| a.contains(b) || b.contains(a))
+    }
+
+    /// Whether some two elves' ranges overlap at all.
+    pub fn has_overlap(&self) -> bool {
+        self.pairs().any(|(a, b)| a.overlaps(b))
+    }
+
+    /// Number of sections assigned to more than one elf of the group.
+    pub fn overlap(&self) -> usize {
+        coverage(&self.elves)
+            .iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(run, _)| run.len())
+            .sum()
+    }
+}
+
+impl FromStr for Group {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let elves = s.split(',').map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(Self { elves })
+    }
+}
+
+/// Groups of `start-end` section ranges, one group per line.
+#[derive(Debug)]
+pub struct Assignments {
+    pub groups: Vec<Group>,
+}
+
+impl FromStr for Assignments {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let groups = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| l.parse().map_err(|e| format!("Line {}: {e}", i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { groups })
+    }
+}
+
+impl Assignments {
+    fn ranges(&self) -> impl Iterator<Item = &Sections> {
+        self.groups.iter().flat_map(|g| &g.elves)
+    }
+
+    /// Sections within the lowest and highest assigned ones that no elf covers.
+    pub fn uncovered(&self) -> Vec<Sections> {
+        coverage(self.ranges())
+            .into_iter()
+            .filter(|(_, count)| *count == 0)
+            .map(|(run, _)| run)
+            .collect()
+    }
+
+    /// The first run of sections covered by the most elves, and how many.
+    pub fn most_contested(&self) -> Option<(Sections, usize)> {
+        coverage(self.ranges())
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+    }
+}
+
+/// Number of groups where one range fully contains another.
+pub fn part1(assignments: &Assignments) -> usize {
+    assignments
+        .groups
+        .iter()
+        .filter(|g| g.has_containment())
+        .count()
+}
+
+/// Number of groups whose ranges overlap at all.
+pub fn part2(assignments: &Assignments) -> usize {
+    assignments
+        .groups
+        .iter()
+        .filter(|g| g.has_overlap())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(s: &str) -> Sections {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn containment_and_overlap() {
+        assert!(sections("2-8").contains(&sections("3-7")));
+        assert!(sections("4-6").contains(&sections("6-6")));
+        assert!(sections("3-7").contains(&sections("3-7")));
+        assert!(!sections("3-7").contains(&sections("2-8")));
+
+        // Ranges sharing only an endpoint overlap, adjacent ones don't.
+        assert!(sections("5-7").overlaps(&sections("7-9")));
+        assert!(sections("7-9").overlaps(&sections("5-7")));
+        assert!(!sections("2-3").overlaps(&sections("4-5")));
+
+        assert_eq!(sections("6-6").len(), 1);
+        assert_eq!(
+            "7-5".parse::<Sections>().unwrap_err(),
+            "Range \"7-5\" ends before it starts"
+        );
+    }
+
+    #[test]
+    fn groups() {
+        let assignments: Assignments = include_str!("../input_small.txt").parse().unwrap();
+        assert_eq!(part1(&assignments), 2);
+        assert_eq!(part2(&assignments), 4);
+
+        let group: Group = "1-4,3-6,6-9".parse().unwrap();
+        assert!(!group.has_containment());
+        assert!(group.has_overlap());
+        assert_eq!(group.overlap(), 3);
+
+        let group: Group = "1-2,4-5,7-9,5-5".parse().unwrap();
+        assert!(group.has_containment());
+        assert_eq!(group.overlap(), 1);
+
+        assert_eq!(
+            "1-2,3-4\n1-2,x-4".parse::<Assignments>().unwrap_err(),
+            "Line 2: Expected integer, got \"x\""
+        );
+    }
+
+    #[test]
+    fn uncovered_sections() {
+        let assignments: Assignments = "2-3,8-9\n2-4,12-12\n3-4,9-10".parse().unwrap();
+
+        assert_eq!(
+            assignments.uncovered(),
+            [sections("5-7"), sections("11-11")]
+        );
+        assert_eq!(assignments.most_contested(), Some((sections("3-3"), 3)));
+
+        let touching: Assignments = "1-3,4-6".parse().unwrap();
+        assert!(touching.uncovered().is_empty());
+    }
+}
@@ -1,46 +1,34 @@
-use std::io::{self, BufRead};
+use aoc::runner::Runner;
+use day04::Assignments;
 
 fn main() {
-    let vecs = io::stdin()
-        .lock()
-        .lines()
-        .map(|l| l.expect("Expected input line"))
-        .map(|l| {
-            l.split(',')
-                .map(|x| {
-                    x.split('-')
-                        .map(|x| x.parse::<i32>().expect("Expected integer"))
-                        .collect::<Vec<i32>>()
-                })
-                .collect::<Vec<Vec<i32>>>()
-        })
-        .collect::<Vec<_>>();
+    let runner = Runner::new(2022, 4);
+    let assignments: Assignments = aoc::read_stdin()
+        .parse()
+        .expect("Couldn't parse assignments");
 
-    let fully_contained_count = vecs
-        .iter()
-        .filter(|pair| {
-            (pair[0][0] >= pair[1][0] && pair[0][1] <= pair[1][1])
-                || (pair[1][0] >= pair[0][0] && pair[1][1] <= pair[0][1])
-        })
-        .count();
+    runner.part(1, "Assignments fully contained in pair", || {
+        day04::part1(&assignments)
+    });
+    runner.part(2, "Assignments with partial overlap", || {
+        day04::part2(&assignments)
+    });
 
-    let partial_overlap_count = vecs
-        .iter()
-        .filter(|pair| {
-            (pair[0][0] >= pair[1][0] && pair[0][0] <= pair[1][1])
-                || (pair[0][1] >= pair[1][0] && pair[0][1] <= pair[1][1])
-                || (pair[1][0] >= pair[0][0] && pair[1][0] <= pair[0][1])
-                || (pair[1][1] >= pair[0][0] && pair[1][1] <= pair[0][1])
-        })
-        .count();
+    let overlap: usize = assignments.groups.iter().map(|g| g.overlap()).sum();
+    println!("Sections assigned to several elves of a group: {overlap}");
 
-    println!(
-        "Assignments fully contained in pair: {}",
-        fully_contained_count
-    );
+    let uncovered: Vec<_> = assignments
+        .uncovered()
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    if uncovered.is_empty() {
+        println!("Every section is covered");
+    } else {
+        println!("Sections nobody covers: {}", uncovered.join(", "));
+    }
 
-    println!(
-        "Assignments with partial overlap: {}",
-        partial_overlap_count
-    );
+    if let Some((sections, elves)) = assignments.most_contested() {
+        println!("Most contested sections: {sections} (covered by {elves} elves)");
+    }
 }
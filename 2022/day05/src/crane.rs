@@ -0,0 +1,293 @@
+//! Crane models and the move log they leave behind.
+
+use crate::{Instruction, Stack};
+use std::fmt::{self, Display, Formatter};
+
+/// How a crane lays down the crates it lifted for one instruction.
+pub trait CrateMover {
+    fn name(&self) -> String;
+
+    /// Given how many crates were lifted, the order they end up in on the
+    /// destination stack, top first, as a permutation of their positions in
+    /// the load, top of the source stack first. `index` is the position of
+    /// the instruction in the procedure.
+    fn arrange(&self, index: usize, len: usize) -> Vec<usize>;
+}
+
+/// Moves crates one at a time, so a load lands reversed.
+pub struct CrateMover9000;
+
+/// Moves a whole load at once, keeping its order.
+pub struct CrateMover9001;
+
+/// Lifts at most `capacity` crates at a time, each batch keeping its order.
+/// A capacity of 1 behaves like the 9000 and an unlimited one like the 9001.
+pub struct Capacity(pub usize);
+
+/// Moves a whole load at once, but its clamp turns the load upside down on
+/// every other instruction, starting with the second one.
+pub struct Alternating;
+
+impl CrateMover for CrateMover9000 {
+    fn name(&self) -> String {
+        "mover 9000".into()
+    }
+
+    fn arrange(&self, _: usize, len: usize) -> Vec<usize> {
+        (0..len).rev().collect()
+    }
+}
+
+impl CrateMover for CrateMover9001 {
+    fn name(&self) -> String {
+        "mover 9001".into()
+    }
+
+    fn arrange(&self, _: usize, len: usize) -> Vec<usize> {
+        (0..len).collect()
+    }
+}
+
+impl CrateMover for Capacity {
+    fn name(&self) -> String {
+        format!("a crane lifting {} crates at most", self.0)
+    }
+
+    fn arrange(&self, _: usize, len: usize) -> Vec<usize> {
+        let positions: Vec<usize> = (0..len).collect();
+
+        positions
+            .chunks(self.0.max(1))
+            .rev()
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+impl CrateMover for Alternating {
+    fn name(&self) -> String {
+        "an alternating crane".into()
+    }
+
+    fn arrange(&self, index: usize, len: usize) -> Vec<usize> {
+        if index % 2 == 1 {
+            (0..len).rev().collect()
+        } else {
+            (0..len).collect()
+        }
+    }
+}
+
+/// An instruction that can't be carried out, with its zero-based index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    pub index: usize,
+    pub instruction: Instruction,
+    pub reason: String,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Instruction {} ({}): {}",
+            self.index + 1,
+            self.instruction,
+            self.reason
+        )
+    }
+}
+
+/// One carried out instruction: the crates lifted from `from`, top first, and
+/// how they were laid on `to`, top first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub index: usize,
+    pub from: usize,
+    pub to: usize,
+    pub lifted: Vec<String>,
+    pub placed: Vec<String>,
+}
+
+/// Stacks being rearranged by a crane, along with the log of the moves so far.
+pub struct Simulation<'a> {
+    pub columns: Vec<Stack>,
+    pub log: Vec<Step>,
+    crane: &'a dyn CrateMover,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(columns: Vec<Stack>, crane: &'a dyn CrateMover) -> Self {
+        Self {
+            columns,
+            log: vec![],
+            crane,
+        }
+    }
+
+    /// Carries out the next instruction, leaving the stacks untouched when it
+    /// is impossible.
+    pub fn step(&mut self, instruction: &Instruction) -> Result<(), MoveError> {
+        let index = self.log.len();
+        let error = |reason: String| MoveError {
+            index,
+            instruction: instruction.clone(),
+            reason,
+        };
+
+        let stacks = self.columns.len();
+        for stack in [instruction.from, instruction.to] {
+            if stack >= stacks {
+                return Err(error(format!(
+                    "there is no stack {}, only {stacks}",
+                    stack + 1
+                )));
+            }
+        }
+
+        let available = self.columns[instruction.from].len();
+        if available < instruction.count {
+            return Err(error(format!(
+                "stack {} only holds {available} crates",
+                instruction.from + 1
+            )));
+        }
+
+        let lifted: Vec<String> = self.columns[instruction.from]
+            .drain(..instruction.count)
+            .collect();
+        let placed: Vec<String> = self
+            .crane
+            .arrange(index, lifted.len())
+            .into_iter()
+            .map(|i| lifted[i].clone())
+            .collect();
+        for c in placed.iter().rev() {
+            self.columns[instruction.to].push_front(c.clone());
+        }
+
+        self.log.push(Step {
+            index,
+            from: instruction.from,
+            to: instruction.to,
+            lifted,
+            placed,
+        });
+
+        Ok(())
+    }
+
+    /// Carries out every instruction, stopping at the first impossible one.
+    pub fn run(&mut self, instructions: &[Instruction]) -> Result<(), MoveError> {
+        instructions.iter().try_for_each(|i| self.step(i))
+    }
+
+    /// Takes back the last move, returning it.
+    pub fn undo(&mut self) -> Option<Step> {
+        let step = self.log.pop()?;
+
+        self.columns[step.to].drain(..step.placed.len());
+        for c in step.lifted.iter().rev() {
+            self.columns[step.from].push_front(c.clone());
+        }
+
+        Some(step)
+    }
+}
+
+/// Runs `instructions` backwards from the `last` stacks, giving the stacks
+/// the procedure must have started from for `crane` to end on `last`.
+///
+/// A crane's arrangement only depends on the size of the load and the
+/// instruction's index, so each move is taken back by inverting it.
+pub fn reverse(
+    last: &[Stack],
+    instructions: &[Instruction],
+    crane: &dyn CrateMover,
+) -> Result<Vec<Stack>, MoveError> {
+    let mut columns = last.to_vec();
+
+    for (index, instruction) in instructions.iter().enumerate().rev() {
+        let error = |reason: String| MoveError {
+            index,
+            instruction: instruction.clone(),
+            reason,
+        };
+
+        let stacks = columns.len();
+        for stack in [instruction.from, instruction.to] {
+            if stack >= stacks {
+                return Err(error(format!(
+                    "there is no stack {}, only {stacks}",
+                    stack + 1
+                )));
+            }
+        }
+
+        let available = columns[instruction.to].len();
+        if available < instruction.count {
+            return Err(error(format!(
+                "stack {} only holds {available} crates to take back",
+                instruction.to + 1
+            )));
+        }
+
+        let order = crane.arrange(index, instruction.count);
+        let placed = columns[instruction.to].drain(..instruction.count);
+
+        let mut lifted = vec![String::new(); instruction.count];
+        for (label, position) in placed.zip(order) {
+            lifted[position] = label;
+        }
+        for c in lifted.into_iter().rev() {
+            columns[instruction.from].push_front(c);
+        }
+    }
+
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Document;
+
+    #[test]
+    fn undo_restores_every_step() {
+        let document: Document = include_str!("../input_small.txt").parse().unwrap();
+
+        for crane in [&Capacity(2) as &dyn CrateMover, &Alternating] {
+            let mut simulation = document.simulate(crane).unwrap();
+            while simulation.undo().is_some() {}
+
+            assert_eq!(simulation.columns, document.columns);
+        }
+    }
+
+    #[test]
+    fn reverse_finds_the_starting_stacks() {
+        let document: Document = include_str!("../input_small.txt").parse().unwrap();
+
+        for crane in [
+            &CrateMover9000 as &dyn CrateMover,
+            &CrateMover9001,
+            &Alternating,
+        ] {
+            let last = document.simulate(crane).unwrap().columns;
+            let first = reverse(&last, &document.instructions, crane).unwrap();
+
+            assert_eq!(first, document.columns);
+        }
+    }
+
+    #[test]
+    fn impossible_moves_are_errors() {
+        let input = include_str!("../input_small.txt").to_string() + "move 5 from 1 to 2\n";
+        let document: Document = input.parse().unwrap();
+
+        let error = crate::top_crates_with(&document, &CrateMover9000).unwrap_err();
+        assert_eq!(error.index, 4);
+        assert_eq!(error.reason, "stack 1 only holds 1 crates");
+    }
+}
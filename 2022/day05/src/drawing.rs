@@ -0,0 +1,196 @@
+//! The ASCII-art drawing of the starting stacks.
+//!
+//! Crates are found by position rather than by a fixed cell width: every
+//! `[label]` belongs to the stack whose number sits under it in the header
+//! line. Labels can be several characters long, lines can be trimmed or
+//! ragged, and stack numbers can have several digits.
+
+use crate::Stack;
+use std::collections::VecDeque;
+
+/// Spans of the non-blank tokens of a line, as inclusive byte offsets.
+fn tokens(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+
+    for (i, c) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                tokens.push((s, i - 1, &line[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+/// Crates of a drawing line, as the span of their brackets and their label.
+fn crates(line: &str, number: usize) -> Result<Vec<(usize, usize, String)>, String> {
+    let mut crates = vec![];
+    let mut rest = line;
+    let mut offset = 0;
+
+    while let Some(open) = rest.find('[') {
+        if !rest[..open].trim().is_empty() {
+            return Err(format!(
+                "Line {number}: unexpected {:?}",
+                rest[..open].trim()
+            ));
+        }
+
+        let close = rest[open..]
+            .find(']')
+            .map(|c| open + c)
+            .ok_or_else(|| format!("Line {number}: unclosed crate"))?;
+        let label = &rest[open + 1..close];
+        if label.is_empty() || label.contains(char::is_whitespace) || label.contains('[') {
+            return Err(format!("Line {number}: invalid crate label {label:?}"));
+        }
+
+        crates.push((offset + open, offset + close, label.to_string()));
+        offset += close + 1;
+        rest = &rest[close + 1..];
+    }
+
+    if !rest.trim().is_empty() {
+        return Err(format!("Line {number}: unexpected {:?}", rest.trim()));
+    }
+
+    Ok(crates)
+}
+
+/// Parses the drawing lines, the last one being the stack numbers, into
+/// stacks listed top crate first.
+pub fn parse(lines: &[&str]) -> Result<Vec<Stack>, String> {
+    let (header, rows) = lines
+        .split_last()
+        .ok_or_else(|| "The drawing is empty".to_string())?;
+
+    let centers: Vec<usize> = tokens(header)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (start, end, number))| match number.parse::<usize>() {
+            Ok(n) if n == i + 1 => Ok(start + (end - start) / 2),
+            _ => Err(format!(
+                "Line {}: expected stack number {}, got {number:?}",
+                lines.len(),
+                i + 1
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+
+    if centers.is_empty() {
+        return Err(format!("Line {}: no stack numbers", lines.len()));
+    }
+
+    let mut columns = vec![VecDeque::new(); centers.len()];
+    for (i, row) in rows.iter().enumerate() {
+        let mut filled = vec![false; centers.len()];
+
+        for (start, end, label) in crates(row, i + 1)? {
+            let column = centers
+                .iter()
+                .position(|&c| (start..=end).contains(&c))
+                .ok_or_else(|| {
+                    format!(
+                        "Line {}: crate [{label}] is not above a stack number",
+                        i + 1
+                    )
+                })?;
+
+            if filled[column] {
+                return Err(format!(
+                    "Line {}: several crates above stack {}",
+                    i + 1,
+                    column + 1
+                ));
+            }
+            filled[column] = true;
+            columns[column].push_back(label);
+        }
+
+        if let Some(column) = (0..centers.len()).find(|&c| !filled[c] && !columns[c].is_empty()) {
+            return Err(format!(
+                "Line {}: stack {} has a crate floating above this empty cell",
+                i + 1,
+                column + 1
+            ));
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Draws the stacks back in the puzzle's format: every cell has the width of
+/// the widest label or stack number plus two, cells are separated by one
+/// space and labels and numbers are centered in them.
+pub fn render(columns: &[Stack]) -> String {
+    let width = columns
+        .iter()
+        .flatten()
+        .map(|label| label.chars().count())
+        .chain([columns.len().to_string().len()])
+        .max()
+        .unwrap_or(1)
+        + 2;
+    let height = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+
+    let center = |text: &str| {
+        let len = text.chars().count();
+        let left = (width - len) / 2;
+        format!(
+            "{}{}{}",
+            " ".repeat(left),
+            text,
+            " ".repeat(width - len - left)
+        )
+    };
+
+    let mut lines: Vec<String> = (0..height)
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| match (row + column.len()).checked_sub(height) {
+                    Some(depth) => center(&format!("[{}]", column[depth])),
+                    None => " ".repeat(width),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+
+    lines.push(
+        (1..=columns.len())
+            .map(|n| center(&n.to_string()))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_drawings_round_trip() {
+        let lines = [
+            "[AB]                                        [Q]",
+            "[C]  [D]                                    [R]  [S]",
+            " 1    2    3   4   5   6   7   8   9   10   11   12",
+        ];
+        let columns = parse(&lines).unwrap();
+
+        assert_eq!(columns.len(), 12);
+        assert_eq!(columns[0], ["AB", "C"]);
+        assert_eq!(columns[10], ["Q", "R"]);
+
+        let rendered = render(&columns);
+        let reparsed: Vec<&str> = rendered.lines().collect();
+        assert_eq!(parse(&reparsed).unwrap(), columns);
+    }
+}
@@ -0,0 +1,193 @@
+//! Day 5: Supply Stacks.
+
+use nom::{
+    bytes::complete::tag, character::complete::digit1, combinator::all_consuming, sequence::tuple,
+    IResult,
+};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+pub mod crane;
+pub mod drawing;
+
+pub use crane::{CrateMover, CrateMover9000, CrateMover9001, MoveError, Simulation};
+
+/// Move `count` crates from stack `from` to stack `to`, both zero-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// A stack of crate labels, top crate first.
+pub type Stack = VecDeque<String>;
+
+/// The starting stacks and the rearrangement procedure.
+#[derive(Debug)]
+pub struct Document {
+    pub columns: Vec<Stack>,
+    pub instructions: Vec<Instruction>,
+}
+
+/// The crate on top of each stack, skipping empty stacks.
+pub fn top_crates(columns: &[Stack]) -> String {
+    columns
+        .iter()
+        .filter_map(|c| c.front())
+        .map(String::as_str)
+        .collect()
+}
+
+impl Document {
+    /// Runs the whole procedure with `crane`, keeping its move log.
+    pub fn simulate<'a>(&self, crane: &'a dyn CrateMover) -> Result<Simulation<'a>, MoveError> {
+        let mut simulation = Simulation::new(self.columns.clone(), crane);
+        simulation.run(&self.instructions)?;
+
+        Ok(simulation)
+    }
+
+    /// The document whose procedure takes `crane` from its starting stacks
+    /// to `last`, checked by running it forward again.
+    pub fn reconstruct(
+        last: &[Stack],
+        instructions: Vec<Instruction>,
+        crane: &dyn CrateMover,
+    ) -> Result<Self, String> {
+        let columns = crane::reverse(last, &instructions, crane).map_err(|e| e.to_string())?;
+        let document = Document {
+            columns,
+            instructions,
+        };
+
+        let replayed = document.simulate(crane).map_err(|e| e.to_string())?;
+        if replayed.columns != last {
+            return Err(format!(
+                "Replaying the reconstruction with {} ends on {:?} instead of {:?}",
+                crane.name(),
+                top_crates(&replayed.columns),
+                top_crates(last)
+            ));
+        }
+
+        Ok(document)
+    }
+}
+
+fn parse_usize(s: &str) -> IResult<&str, usize> {
+    use nom::combinator::map;
+    map(digit1, |i: &str| {
+        i.parse::<usize>().expect("Invalid number")
+    })(s)
+}
+
+fn instruction_line(s: &str) -> IResult<&str, (usize, usize, usize)> {
+    let (s, (_, count, _, from, _, to)) = tuple((
+        tag("move "),
+        parse_usize,
+        tag(" from "),
+        parse_usize,
+        tag(" to "),
+        parse_usize,
+    ))(s)?;
+
+    Ok((s, (count, from, to)))
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match all_consuming(instruction_line)(s.trim_end()) {
+            Ok((_, (count, from, to))) if from > 0 && to > 0 => Ok(Instruction {
+                count,
+                from: from - 1,
+                to: to - 1,
+            }),
+            Ok(_) => Err(format!("Stacks are numbered from 1: {s:?}")),
+            Err(_) => Err(format!(
+                "Expected move <count> from <stack> to <stack>: {s:?}"
+            )),
+        }
+    }
+}
+
+impl FromStr for Document {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let blank = lines
+            .iter()
+            .position(|l| l.trim().is_empty())
+            .ok_or_else(|| "Expected a blank line after the drawing".to_string())?;
+
+        let columns = drawing::parse(&lines[..blank])?;
+        let instructions = parse_instructions(&lines[blank + 1..], blank + 2)?;
+
+        Ok(Document {
+            columns,
+            instructions,
+        })
+    }
+}
+
+/// The instructions on `lines`, the first of which is line `first` of the
+/// input, skipping blank lines.
+fn parse_instructions(lines: &[&str], first: usize) -> Result<Vec<Instruction>, String> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| l.parse().map_err(|e| format!("Line {}: {e}", first + i)))
+        .collect()
+}
+
+/// A procedure on its own, without the drawing, one instruction per line.
+pub fn instructions(s: &str) -> Result<Vec<Instruction>, String> {
+    parse_instructions(&s.lines().collect::<Vec<_>>(), 1)
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", drawing::render(&self.columns))?;
+        for instruction in &self.instructions {
+            writeln!(f, "{instruction}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Top crates once `crane` carried out the whole procedure.
+pub fn top_crates_with(document: &Document, crane: &dyn CrateMover) -> Result<String, MoveError> {
+    document
+        .simulate(crane)
+        .map(|simulation| top_crates(&simulation.columns))
+}
+
+/// Top crates after the CrateMover 9000 moves crates one at a time.
+pub fn part1(document: &Document) -> Result<String, MoveError> {
+    top_crates_with(document, &CrateMover9000)
+}
+
+/// Top crates after the CrateMover 9001 moves crates all at once.
+pub fn part2(document: &Document) -> Result<String, MoveError> {
+    top_crates_with(document, &CrateMover9001)
+}
@@ -1,166 +1,97 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{anychar, digit1},
-    multi::{many1, separated_list1},
-    sequence::{delimited, terminated, tuple},
-    IResult,
-};
-use std::{
-    collections::VecDeque,
-    io::{self, Read},
+use aoc::runner::Runner;
+use day05::{
+    crane::{Alternating, Capacity},
+    drawing, CrateMover, CrateMover9000, CrateMover9001, Document,
 };
+use std::{env, fs, process};
 
-#[derive(Debug)]
-struct Instruction {
-    count: usize,
-    from: usize,
-    to: usize,
+fn usage() -> ! {
+    eprintln!("Usage: day05 [--crane 9000|9001|alternating|capacity:<crates>] [--render] [--reverse <final drawing>] < <document or instructions>");
+    process::exit(1);
 }
 
-#[derive(Debug)]
-struct Document {
-    columns: Vec<VecDeque<char>>,
-    instructions: Vec<Instruction>,
+fn crane(model: &str) -> Box<dyn CrateMover> {
+    match model {
+        "9000" => Box::new(CrateMover9000),
+        "9001" => Box::new(CrateMover9001),
+        "alternating" => Box::new(Alternating),
+        _ => match model.strip_prefix("capacity:").map(str::parse) {
+            Some(Ok(capacity)) if capacity > 0 => Box::new(Capacity(capacity)),
+            _ => usage(),
+        },
+    }
 }
 
-fn top_crates(columns: Vec<VecDeque<char>>) -> String {
-    columns.iter().map(|c| c[0]).collect()
+/// Prints the document that ends on the drawing in `path`. `input` is either
+/// the procedure on its own or a whole document, whose drawing is ignored.
+fn reconstruct(input: &str, path: &str, crane: &dyn CrateMover) -> Result<(), String> {
+    let drawing = fs::read_to_string(path).map_err(|e| format!("Couldn't read {path}: {e}"))?;
+    let lines: Vec<&str> = drawing.lines().filter(|l| !l.trim().is_empty()).collect();
+    let last = drawing::parse(&lines).map_err(|e| format!("{path}: {e}"))?;
+
+    let has_drawing = input
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .is_some_and(|l| !l.starts_with("move "));
+    let instructions = if has_drawing {
+        input.parse::<Document>()?.instructions
+    } else {
+        day05::instructions(input)?
+    };
+
+    print!("{}", Document::reconstruct(&last, instructions, crane)?);
+    Ok(())
 }
 
-impl Document {
-    pub fn simulate_9000(&self) -> String {
-        let mut columns = self.columns.clone();
-
-        for instruction in &self.instructions {
-            for _ in 0..(instruction.count) {
-                if let Some(c) = columns[instruction.from].pop_front() {
-                    columns[instruction.to].push_front(c);
-                }
-            }
+fn main() {
+    let mut extra = None;
+    let mut render = false;
+    let mut reverse = None;
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--crane" => extra = Some(crane(args.next().unwrap_or_else(|| usage()))),
+            "--render" => render = true,
+            "--reverse" => reverse = Some(args.next().unwrap_or_else(|| usage()).clone()),
+            _ => usage(),
         }
-
-        top_crates(columns)
     }
 
-    pub fn simulate_9001(&self) -> String {
-        let mut columns = self.columns.clone();
+    let runner = Runner::new(2022, 5);
+    let input = aoc::read_stdin();
 
-        for instruction in &self.instructions {
-            let crates: Vec<char> = columns[instruction.from]
-                .drain(0..instruction.count)
-                .collect();
-            crates
-                .iter()
-                .enumerate()
-                .for_each(|(i, c)| columns[instruction.to].insert(i, *c));
+    if let Some(path) = reverse {
+        let crane = extra.unwrap_or_else(|| Box::new(CrateMover9000));
+        if let Err(e) = reconstruct(&input, &path, crane.as_ref()) {
+            eprintln!("{e}");
+            process::exit(1);
         }
-
-        top_crates(columns)
+        return;
     }
-}
-
-fn parse_crate(s: &str) -> IResult<&str, Option<char>> {
-    let (s, c) = delimited(tag("["), anychar, tag("]"))(s)?;
-    Ok((s, Some(c)))
-}
-
-fn parse_no_crate(s: &str) -> IResult<&str, Option<char>> {
-    let (s, _) = tuple((tag(" "), tag(" "), tag(" ")))(s)?;
-    Ok((s, None))
-}
-
-fn crate_line(s: &str) -> IResult<&str, Vec<Option<char>>> {
-    separated_list1(tag(" "), alt((parse_crate, parse_no_crate)))(s)
-}
-
-fn crate_lines(s: &str) -> IResult<&str, Vec<Vec<Option<char>>>> {
-    many1(terminated(crate_line, tag("\n")))(s)
-}
-
-fn parse_usize(s: &str) -> IResult<&str, usize> {
-    use nom::combinator::map;
-    map(digit1, |i: &str| {
-        i.parse::<usize>().expect("Invalid number")
-    })(s)
-}
 
-fn columns_line(s: &str) -> IResult<&str, Vec<usize>> {
-    terminated(
-        delimited(
-            tag(" "),
-            separated_list1(many1(tag(" ")), parse_usize),
-            tag(" "),
-        ),
-        tag("\n"),
-    )(s)
-}
-
-fn instruction_line(s: &str) -> IResult<&str, Instruction> {
-    let (s, (_, count, _, from, _, to)) = tuple((
-        tag("move "),
-        parse_usize,
-        tag(" from "),
-        parse_usize,
-        tag(" to "),
-        parse_usize,
-    ))(s)?;
-
-    Ok((
-        s,
-        Instruction {
-            count,
-            from: from - 1,
-            to: to - 1,
-        },
-    ))
-}
-
-fn instruction_lines(s: &str) -> IResult<&str, Vec<Instruction>> {
-    many1(terminated(instruction_line, tag("\n")))(s)
-}
-
-fn transform_crates(column_count: usize, lines: Vec<Vec<Option<char>>>) -> Vec<VecDeque<char>> {
-    lines
-        .iter()
-        .fold(vec![VecDeque::new(); column_count], |mut columns, line| {
-            for i in 0..column_count {
-                if let Some(c) = line[i] {
-                    columns[i].push_back(c);
-                }
-            }
-            columns
-        })
-}
+    let document: Document = input.parse().expect("Failed to parse document");
 
-fn parse(s: &str) -> IResult<&str, Document> {
-    let (s, crate_lines) = crate_lines(s)?;
-    let (s, columns) = columns_line(s)?;
-    let column_count = columns.last().expect("No column count detected");
-    let crate_columns = transform_crates(*column_count, crate_lines);
-    let (s, _) = tag("\n")(s)?;
-    let (s, instructions) = instruction_lines(s)?;
-
-    Ok((
-        s,
-        Document {
-            columns: crate_columns,
-            instructions,
-        },
-    ))
-}
-
-fn main() {
-    let mut input = String::new();
-    io::stdin()
-        .lock()
-        .read_to_string(&mut input)
-        .expect("Couldn't read stdin");
+    if render {
+        print!("{document}");
+        return;
+    }
 
-    let (_, document) = parse(&input).expect("Failed to parse document");
-    let top_9000 = document.simulate_9000();
-    let top_9001 = document.simulate_9001();
+    let parts = runner
+        .try_part(1, "Top crates using mover 9000", || day05::part1(&document))
+        .and_then(|_| {
+            runner.try_part(2, "Top crates using mover 9001", || day05::part2(&document))
+        });
+    if let Err(e) = parts {
+        eprintln!("{e}");
+        process::exit(1);
+    }
 
-    println!("Top crates using mover 9000: {}", top_9000);
-    println!("Top crates using mover 9001: {}", top_9001);
+    if let Some(crane) = extra {
+        match day05::top_crates_with(&document, crane.as_ref()) {
+            Ok(top) => println!("Top crates using {}: {top}", crane.name()),
+            Err(e) => eprintln!("{e}"),
+        }
+    }
 }
@@ -0,0 +1,296 @@
+//! Day 6: Tuning Trouble.
+
+use std::{
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    io::{self, BufRead, BufReader, Read},
+    str::FromStr,
+};
+
+/// Number of characters read once a marker was complete, if there is one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Marker {
+    At(usize),
+    Missing,
+}
+
+impl Display for Marker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Marker::At(position) => write!(f, "{position}"),
+            Marker::Missing => f.write_str("no marker"),
+        }
+    }
+}
+
+/// A window of the last `size` bytes, with how many times each byte occurs
+/// in it and how many byte values occur more than once.
+struct Window {
+    size: usize,
+    counts: [u32; 256],
+    repeated: usize,
+    marker: Marker,
+}
+
+/// Sliding-window marker detector fed one byte at a time.
+///
+/// Every window size keeps its own 256-entry count table, so each byte costs
+/// one constant-time update per window, however large the windows are, and
+/// only the last bytes of the largest window are kept around. It also follows
+/// where each byte was last seen, for the first repeated byte and the longest
+/// run of distinct bytes.
+pub struct Detector {
+    windows: Vec<Window>,
+    recent: Vec<u8>,
+    position: usize,
+    last_seen: [usize; 256],
+    first_duplicate: Option<usize>,
+    run_start: usize,
+    longest_distinct: usize,
+}
+
+impl Detector {
+    pub fn new(sizes: &[usize]) -> Self {
+        let windows = sizes
+            .iter()
+            .map(|&size| Window {
+                size,
+                counts: [0; 256],
+                repeated: 0,
+                marker: if size == 0 {
+                    Marker::At(0)
+                } else {
+                    Marker::Missing
+                },
+            })
+            .collect();
+        let longest = sizes.iter().copied().max().unwrap_or(0).max(1);
+
+        Self {
+            windows,
+            recent: vec![0; longest],
+            position: 0,
+            last_seen: [0; 256],
+            first_duplicate: None,
+            run_start: 0,
+            longest_distinct: 0,
+        }
+    }
+
+    pub fn push(&mut self, byte: u8) {
+        let longest = self.recent.len();
+
+        for window in &mut self.windows {
+            if window.size == 0 || window.marker != Marker::Missing {
+                continue;
+            }
+
+            if self.position >= window.size {
+                let leaving = self.recent[(self.position - window.size) % longest] as usize;
+                window.counts[leaving] -= 1;
+                if window.counts[leaving] == 1 {
+                    window.repeated -= 1;
+                }
+            }
+
+            window.counts[byte as usize] += 1;
+            if window.counts[byte as usize] == 2 {
+                window.repeated += 1;
+            }
+
+            if self.position + 1 >= window.size && window.repeated == 0 {
+                window.marker = Marker::At(self.position + 1);
+            }
+        }
+
+        let seen = self.last_seen[byte as usize];
+        if seen > 0 {
+            self.first_duplicate.get_or_insert(self.position + 1);
+            self.run_start = self.run_start.max(seen);
+        }
+        self.last_seen[byte as usize] = self.position + 1;
+        self.longest_distinct = self
+            .longest_distinct
+            .max(self.position + 1 - self.run_start);
+
+        self.recent[self.position % longest] = byte;
+        self.position += 1;
+    }
+
+    /// Whether every window size already found its marker.
+    pub fn is_done(&self) -> bool {
+        self.windows.iter().all(|w| w.marker != Marker::Missing)
+    }
+
+    /// The marker of each window size, in the order they were given.
+    pub fn markers(&self) -> Vec<Marker> {
+        self.windows.iter().map(|w| w.marker).collect()
+    }
+
+    /// Number of characters read once one of them was a repeat.
+    pub fn first_duplicate(&self) -> Option<usize> {
+        self.first_duplicate
+    }
+
+    /// Length of the longest run of distinct characters so far.
+    pub fn longest_distinct(&self) -> usize {
+        self.longest_distinct
+    }
+}
+
+/// What a whole datastream holds, for a list of marker sizes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub markers: Vec<Marker>,
+    pub first_duplicate: Option<usize>,
+    pub longest_distinct: usize,
+}
+
+/// A report for each non-blank line read from `reader`, every line being a
+/// datastream of its own.
+pub fn reports(reader: impl Read, sizes: &[usize]) -> io::Result<Vec<Report>> {
+    let mut reports = vec![];
+    let mut detector = Detector::new(sizes);
+
+    let mut finish = |detector: &mut Detector| {
+        if detector.position > 0 {
+            reports.push(Report {
+                markers: detector.markers(),
+                first_duplicate: detector.first_duplicate(),
+                longest_distinct: detector.longest_distinct(),
+            });
+        }
+        *detector = Detector::new(sizes);
+    };
+
+    for byte in BufReader::new(reader).bytes() {
+        match byte? {
+            b'\n' | b'\r' => finish(&mut detector),
+            byte => detector.push(byte),
+        }
+    }
+    finish(&mut detector);
+
+    Ok(reports)
+}
+
+/// Markers for each of `sizes` in the first line read from `reader`, in a
+/// single pass that stops as soon as they are all found.
+pub fn find_markers(reader: impl Read, sizes: &[usize]) -> io::Result<Vec<Marker>> {
+    let mut reader = BufReader::new(reader);
+    let mut detector = Detector::new(sizes);
+
+    'read: loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+
+        let len = buffer.len();
+        for &byte in buffer {
+            if byte == b'\n' || byte == b'\r' {
+                break 'read;
+            }
+
+            detector.push(byte);
+            if detector.is_done() {
+                break 'read;
+            }
+        }
+        reader.consume(len);
+    }
+
+    Ok(detector.markers())
+}
+
+/// Number of characters read once the last `length` characters are all distinct.
+pub fn find_marker(s: &str, length: usize) -> Marker {
+    find_markers(s.as_bytes(), &[length]).expect("Reading from memory can't fail")[0]
+}
+
+/// The datastream buffers, one per non-blank line of input.
+#[derive(Debug)]
+pub struct Signal {
+    pub buffers: Vec<String>,
+}
+
+impl FromStr for Signal {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            buffers: s
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(String::from)
+                .collect(),
+        })
+    }
+}
+
+/// Position of the marker of `length`, named `name`, in the puzzle's
+/// datastream, which is the first one.
+fn puzzle_marker(signal: &Signal, length: usize, name: &str) -> Result<usize, String> {
+    let buffer = signal.buffers.first().ok_or("No datastream in the input")?;
+    match find_marker(buffer, length) {
+        Marker::At(position) => Ok(position),
+        Marker::Missing => Err(format!("No {name} marker in the datastream")),
+    }
+}
+
+/// Position of the start-of-packet marker.
+pub fn part1(signal: &Signal) -> Result<usize, String> {
+    puzzle_marker(signal, 4, "start-of-packet")
+}
+
+/// Position of the start-of-message marker.
+pub fn part2(signal: &Signal) -> Result<usize, String> {
+    puzzle_marker(signal, 14, "start-of-message")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markers_in_one_pass() {
+        let markers = find_markers("mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes(), &[4, 14, 40]);
+
+        assert_eq!(
+            markers.unwrap(),
+            [Marker::At(7), Marker::At(19), Marker::Missing]
+        );
+        assert_eq!(find_marker("abcd", 4), Marker::At(4));
+        assert_eq!(find_marker("ab", 4), Marker::Missing);
+    }
+
+    #[test]
+    fn parts_answer_for_the_first_datastream() {
+        let signal: Signal = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\nabcd\n".parse().unwrap();
+
+        assert_eq!(part1(&signal), Ok(7));
+        assert_eq!(part2(&signal), Ok(19));
+        assert!(part1(&"aaaa".parse().unwrap()).is_err());
+    }
+
+    #[test]
+    fn every_line_is_a_datastream() {
+        let reports = reports("abcabcdd\n\nxyz\n".as_bytes(), &[4]).unwrap();
+
+        assert_eq!(
+            reports,
+            [
+                Report {
+                    markers: vec![Marker::At(7)],
+                    first_duplicate: Some(4),
+                    longest_distinct: 4,
+                },
+                Report {
+                    markers: vec![Marker::Missing],
+                    first_duplicate: None,
+                    longest_distinct: 3,
+                },
+            ]
+        );
+    }
+}
@@ -1,30 +1,41 @@
-use std::{
-    collections::HashSet,
-    io::{self, BufRead},
-    str::Chars,
-};
+use aoc::runner::Runner;
+use day06::Signal;
+use std::process;
 
-fn has_unique_elements(chars: Chars) -> bool {
-    let mut uniq = HashSet::new();
-    chars.into_iter().all(move |x| uniq.insert(x))
-}
+fn main() {
+    let runner = Runner::new(2022, 6);
+    let input = aoc::read_stdin();
+    let signal: Signal = input.parse().unwrap();
 
-fn find_marker(s: &str, length: usize) -> usize {
-    for i in 0..(s.len() - length) {
-        if has_unique_elements(s[i..(i + length)].chars()) {
-            return i + length;
-        }
+    let answers = runner
+        .try_part(1, "Packet marker at position", || day06::part1(&signal))
+        .and_then(|_| runner.try_part(2, "Message marker at position", || day06::part2(&signal)));
+    if let Err(e) = &answers {
+        eprintln!("{e}");
     }
-    unreachable!()
-}
 
-fn main() {
-    let lines: Vec<String> = io::stdin()
-        .lock()
-        .lines()
-        .map(|x| x.expect("Failed to read stdin"))
-        .collect();
+    // Every line is a datastream of its own, reported apart from the
+    // puzzle's answers.
 
-    println!("Packet marker at position: {}", find_marker(&lines[0], 4));
-    println!("Message marker at position: {}", find_marker(&lines[0], 14));
+    let reports =
+        day06::reports(input.as_bytes(), &[4, 14]).expect("Reading from memory can't fail");
+    for (i, report) in reports.iter().enumerate() {
+        let duplicate = match report.first_duplicate {
+            Some(position) => position.to_string(),
+            None => "none".into(),
+        };
+
+        println!(
+            "Stream {}: packet {}, message {}, first duplicate at {}, longest distinct run {}",
+            i + 1,
+            report.markers[0],
+            report.markers[1],
+            duplicate,
+            report.longest_distinct
+        );
+    }
+
+    if answers.is_err() {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,211 @@
+//! The directory tree a terminal session explored.
+//!
+//! Nodes live in one vector and refer to each other by index, so a node's
+//! parent always comes before it. Directories keep their entries by name,
+//! which is what makes listing the same directory twice harmless.
+
+use std::collections::BTreeMap;
+
+/// Index of a node in its `FileSystem`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub usize);
+
+#[derive(Debug, Clone)]
+pub struct Dir {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub children: BTreeMap<String, NodeId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct File {
+    pub name: String,
+    pub parent: NodeId,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Node {
+    Dir(Dir),
+    File(File),
+}
+
+impl Node {
+    pub fn name(&self) -> &str {
+        match self {
+            Node::Dir(dir) => &dir.name,
+            Node::File(file) => &file.name,
+        }
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        match self {
+            Node::Dir(dir) => dir.parent,
+            Node::File(file) => Some(file.parent),
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self, Node::Dir(_))
+    }
+}
+
+/// A tree of directories and files rooted at `/`.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node::Dir(Dir {
+                name: String::new(),
+                parent: None,
+                children: BTreeMap::new(),
+            })],
+        }
+    }
+}
+
+impl FileSystem {
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    /// Every node, parents before their children.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    pub fn directories(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.ids().filter(|&id| self.node(id).is_dir())
+    }
+
+    /// Entries of a directory sorted by name, none for a file.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let children = match self.node(id) {
+            Node::Dir(dir) => Some(dir.children.values().copied()),
+            Node::File(_) => None,
+        };
+
+        children.into_iter().flatten()
+    }
+
+    fn entry(&mut self, parent: NodeId, node: Node) -> Result<NodeId, String> {
+        let name = node.name().to_string();
+        if name.is_empty() || name == "." || name == ".." || name.contains('/') {
+            return Err(format!("Invalid name {name:?}"));
+        }
+
+        let id = NodeId(self.nodes.len());
+        let Node::Dir(dir) = &mut self.nodes[parent.0] else {
+            return Err(format!("{} is not a directory", self.path(parent)));
+        };
+
+        if let Some(&existing) = dir.children.get(&name) {
+            return match (&mut self.nodes[existing.0], node) {
+                (Node::Dir(_), Node::Dir(_)) => Ok(existing),
+                (Node::File(file), Node::File(listed)) => {
+                    file.size = listed.size;
+                    Ok(existing)
+                }
+                _ => Err(format!(
+                    "{} is listed both as a file and as a directory",
+                    self.path(existing)
+                )),
+            };
+        }
+
+        dir.children.insert(name, id);
+        self.nodes.push(node);
+
+        Ok(id)
+    }
+
+    /// The directory `name` in `parent`, created if it wasn't seen yet.
+    pub fn add_dir(&mut self, parent: NodeId, name: &str) -> Result<NodeId, String> {
+        self.entry(
+            parent,
+            Node::Dir(Dir {
+                name: name.to_string(),
+                parent: Some(parent),
+                children: BTreeMap::new(),
+            }),
+        )
+    }
+
+    /// The file `name` in `parent`, created if it wasn't seen yet. Listing a
+    /// file again updates its size rather than adding it twice.
+    pub fn add_file(&mut self, parent: NodeId, name: &str, size: usize) -> Result<NodeId, String> {
+        self.entry(
+            parent,
+            Node::File(File {
+                name: name.to_string(),
+                parent,
+                size,
+            }),
+        )
+    }
+
+    /// Size of every node, the total of their contents for directories,
+    /// indexed by node.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self
+            .nodes
+            .iter()
+            .map(|node| match node {
+                Node::File(file) => file.size,
+                Node::Dir(_) => 0,
+            })
+            .collect();
+
+        for (i, node) in self.nodes.iter().enumerate().rev() {
+            if let Some(parent) = node.parent() {
+                sizes[parent.0] += sizes[i];
+            }
+        }
+
+        sizes
+    }
+
+    /// Total size of a node and everything below it.
+    pub fn size(&self, id: NodeId) -> usize {
+        match self.node(id) {
+            Node::File(file) => file.size,
+            Node::Dir(_) => self.children(id).map(|child| self.size(child)).sum(),
+        }
+    }
+
+    /// Absolute path of a node, `/` for the root.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![];
+        let mut current = Some(id);
+        while let Some(id) = current {
+            names.push(self.node(id).name());
+            current = self.node(id).parent();
+        }
+
+        match names.len() {
+            1 => "/".into(),
+            _ => names.into_iter().rev().collect::<Vec<_>>().join("/"),
+        }
+    }
+
+    /// The node at an absolute path.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        let relative = path.strip_prefix('/')?;
+
+        relative
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(self.root(), |id, name| match self.node(id) {
+                Node::Dir(dir) => dir.children.get(name).copied(),
+                Node::File(_) => None,
+            })
+    }
+}
@@ -0,0 +1,163 @@
+//! Day 7: No Space Left On Device.
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, not_line_ending, space1},
+    combinator::{all_consuming, map, map_res},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+use std::str::FromStr;
+
+pub mod fs;
+pub mod report;
+pub mod transcript;
+
+pub use fs::{FileSystem, Node, NodeId};
+
+const DISK_SIZE: usize = 70_000_000;
+const UPDATE_SIZE: usize = 30_000_000;
+
+/// One line of the terminal session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(usize, &'a str),
+}
+
+fn file(s: &str) -> IResult<&str, Line<'_>> {
+    map(
+        separated_pair(parse_usize, space1, not_line_ending),
+        |(size, name)| Line::File(size, name),
+    )(s)
+}
+
+fn dir(s: &str) -> IResult<&str, Line<'_>> {
+    map(preceded(tag("dir "), not_line_ending), Line::Dir)(s)
+}
+
+fn ls_command(s: &str) -> IResult<&str, Line<'_>> {
+    map(tag("$ ls"), |_| Line::Ls)(s)
+}
+
+fn cd_command(s: &str) -> IResult<&str, Line<'_>> {
+    map(preceded(tag("$ cd "), not_line_ending), Line::Cd)(s)
+}
+
+fn parse_usize(s: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse)(s)
+}
+
+/// Parses one line of the terminal session.
+pub fn parse_line(line: &str) -> Result<Line<'_>, String> {
+    all_consuming(alt((file, cd_command, ls_command, dir)))(line)
+        .map(|(_, line)| line)
+        .map_err(|_| format!("Unexpected {line:?}"))
+}
+
+impl FromStr for FileSystem {
+    type Err = String;
+
+    /// Replays the `$ cd` and `$ ls` commands of a terminal session.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fs = FileSystem::default();
+        let mut current = fs.root();
+
+        for (i, line) in s.lines().enumerate() {
+            let error = |e: String| format!("Line {}: {e}", i + 1);
+
+            match parse_line(line).map_err(error)? {
+                Line::Cd("/") => current = fs.root(),
+                Line::Cd("..") => current = fs.node(current).parent().unwrap_or(fs.root()),
+                Line::Cd(name) => current = fs.add_dir(current, name).map_err(error)?,
+                Line::Ls => {}
+                Line::Dir(name) => {
+                    fs.add_dir(current, name).map_err(error)?;
+                }
+                Line::File(size, name) => {
+                    fs.add_file(current, name, size).map_err(error)?;
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+}
+
+/// Space still free on the disk.
+pub fn free_space(fs: &FileSystem) -> usize {
+    DISK_SIZE.saturating_sub(fs.size(fs.root()))
+}
+
+/// Space to free before the update fits.
+pub fn missing_space(fs: &FileSystem) -> usize {
+    UPDATE_SIZE.saturating_sub(free_space(fs))
+}
+
+/// Sum of the sizes of all directories of at most 100000.
+pub fn part1(fs: &FileSystem) -> usize {
+    let sizes = fs.sizes();
+
+    fs.directories()
+        .map(|id| sizes[id.0])
+        .filter(|&size| size <= 100_000)
+        .sum()
+}
+
+/// The smallest directory that frees enough space for the update, and its
+/// size.
+pub fn smallest_deletion(fs: &FileSystem) -> Option<(NodeId, usize)> {
+    let sizes = fs.sizes();
+    let missing_space = missing_space(fs);
+
+    fs.directories()
+        .map(|id| (id, sizes[id.0]))
+        .filter(|&(_, size)| size >= missing_space)
+        .min_by_key(|&(_, size)| size)
+}
+
+/// Size of the smallest directory that frees enough space for the update.
+pub fn part2(fs: &FileSystem) -> usize {
+    smallest_deletion(fs)
+        .map(|(_, size)| size)
+        .expect("Couldn't find the smallest dir")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relisting_and_returning_to_the_root() {
+        let fs: FileSystem = "$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c\ndir empty\n\
+                              $ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n7 c"
+            .parse()
+            .unwrap();
+
+        assert_eq!(fs.size(fs.root()), 17);
+        assert_eq!(fs.sizes()[0], 17);
+        assert_eq!(fs.children(fs.root()).count(), 2);
+
+        let empty = fs.lookup("/a/empty").unwrap();
+        assert_eq!(fs.path(empty), "/a/empty");
+        assert_eq!(fs.size(empty), 0);
+        assert_eq!(fs.lookup("/a/c").map(|c| fs.size(c)), Some(7));
+        assert_eq!(fs.lookup("/b/c"), None);
+    }
+
+    #[test]
+    fn deleting_exactly_the_missing_space() {
+        let fs: FileSystem = "$ cd /\n$ ls\n40000000 big\ndir a\n$ cd a\n$ ls\n10000000 c"
+            .parse()
+            .unwrap();
+
+        assert_eq!(missing_space(&fs), 10_000_000);
+        assert_eq!(
+            smallest_deletion(&fs),
+            Some((fs.lookup("/a").unwrap(), 10_000_000))
+        );
+    }
+}
@@ -1,181 +1,99 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{digit1, newline, not_line_ending, space1},
-    sequence::{terminated, tuple},
-    IResult,
-};
-use std::{
-    collections::HashMap,
-    io::{self, Read},
-};
-
-enum ParseOutput<'a> {
-    File(File<'a>),
-    Directory(String),
-    None,
-}
-
-#[derive(Debug, Default)]
-struct File<'a> {
-    size: usize,
-    path: String,
-    _pd: std::marker::PhantomData<&'a str>,
-}
-
-impl<'a> File<'a> {
-    pub fn get_directories(&'a self) -> Vec<&'a str> {
-        self.path
-            .chars()
-            .enumerate()
-            .filter(|(_, c)| *c == '/')
-            .map(|(i, _)| {
-                if i == 0 {
-                    &self.path[..1]
-                } else {
-                    &self.path[..i]
-                }
-            })
-            .collect()
-    }
-
-    pub fn get_size(&'a self) -> usize {
-        self.size
-    }
-}
-
-fn file<'a>(parent_dir: String) -> impl Fn(&'a str) -> IResult<&'a str, ParseOutput> {
-    move |s: &str| {
-        let (s, (size, _, filename)) =
-            terminated(tuple((parse_usize, space1, not_line_ending)), newline)(s)?;
-
-        let path = match parent_dir.as_ref() {
-            "/" => format!("/{}", filename),
-            _ => format!("{}/{}", parent_dir, filename),
-        };
-
-        Ok((
-            s,
-            ParseOutput::File(File {
-                size,
-                path,
-                ..Default::default()
-            }),
-        ))
-    }
+use aoc::runner::Runner;
+use day07::{report, transcript, FileSystem};
+use std::{env, path::PathBuf, process};
+
+fn usage() -> ! {
+    eprintln!("Usage: day07 [--tree | --du <depth> | --json | --find [--type d|f] [--min-size <size>] [--max-size <size>] [--name <pattern>]]");
+    eprintln!("       day07 --generate <directory> [--seed <seed>]");
+    process::exit(1);
 }
 
-fn dir(s: &str) -> IResult<&str, ParseOutput> {
-    let (s, _) = terminated(tuple((tag("dir "), not_line_ending)), newline)(s)?;
-
-    Ok((s, ParseOutput::None))
+enum Command {
+    Tree,
+    Du(usize),
+    Json,
+    Find,
 }
 
-fn ls_command(s: &str) -> IResult<&str, ParseOutput> {
-    let (s, _) = terminated(tag("$ ls"), newline)(s)?;
-
-    Ok((s, ParseOutput::None))
+fn number(arg: Option<&String>) -> usize {
+    arg.and_then(|n| n.parse().ok()).unwrap_or_else(|| usage())
 }
 
-fn cd_command<'a>(current_dir: String) -> impl Fn(&'a str) -> IResult<&'a str, ParseOutput> {
-    move |s: &str| {
-        let (s, (_, target_dir)) = terminated(tuple((tag("$ cd "), not_line_ending)), newline)(s)?;
-
-        let mut current_dir = current_dir.to_owned();
-
-        match (current_dir.as_str(), target_dir) {
-            (_, "..") => {
-                if let Some(pos) = current_dir.rfind('/') {
-                    if pos != 0 {
-                        current_dir.truncate(pos);
-                    } else {
-                        current_dir.truncate(1);
-                    }
+fn main() {
+    let mut command = None;
+    let mut filter = report::Filter::default();
+    let mut generate = None;
+    let mut seed = 1;
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--tree" => command = Some(Command::Tree),
+            "--du" => command = Some(Command::Du(number(args.next()))),
+            "--json" => command = Some(Command::Json),
+            "--find" => command = Some(Command::Find),
+            "--generate" => generate = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "--seed" => seed = number(args.next()) as u64,
+            "--type" => {
+                filter.directories = match args.next().map(String::as_str) {
+                    Some("d") => Some(true),
+                    Some("f") => Some(false),
+                    _ => usage(),
                 }
             }
-            ("", _) => current_dir.push('/'),
-            ("/", _) => current_dir.push_str(target_dir),
-            _ => {
-                current_dir.push('/');
-                current_dir.push_str(target_dir)
-            }
+            "--min-size" => filter.min_size = Some(number(args.next())),
+            "--max-size" => filter.max_size = Some(number(args.next())),
+            "--name" => filter.name = Some(args.next().unwrap_or_else(|| usage()).clone()),
+            _ => usage(),
         }
-
-        Ok((s, ParseOutput::Directory(current_dir)))
     }
-}
-
-fn parse_usize(s: &str) -> IResult<&str, usize> {
-    use nom::combinator::map;
-
-    map(digit1, |i: &str| {
-        i.parse::<usize>().expect("Invalid number")
-    })(s)
-}
 
-fn parse(input: &str) -> IResult<&str, Vec<File>> {
-    let mut files = vec![];
-    let mut current_dir = String::new();
-    let mut pos: usize = 0;
-
-    loop {
-        let cd1 = current_dir.clone();
-        let cd2 = current_dir.clone();
-        let (s, output) = alt((file(cd1), cd_command(cd2), ls_command, dir))(&input[pos..])?;
-        pos = input.len() - s.len();
-
-        match output {
-            ParseOutput::File(f) => files.push(f),
-            ParseOutput::Directory(d) => current_dir = d,
-            _ => {}
+    if let Some(directory) = generate {
+        match transcript::from_dir(&directory, seed) {
+            Ok(transcript) => print!("{transcript}"),
+            Err(e) => {
+                eprintln!("Couldn't read {}: {e}", directory.display());
+                process::exit(1);
+            }
         }
+        return;
+    }
 
-        if s.is_empty() {
-            return Ok((s, files));
+    let runner = Runner::new(2022, 7);
+    let fs: FileSystem = aoc::read_stdin()
+        .parse()
+        .expect("Failed to parse terminal session");
+
+    if let Some(command) = command {
+        match command {
+            Command::Tree => print!("{}", report::tree(&fs)),
+            Command::Du(depth) => print!("{}", report::du(&fs, depth)),
+            Command::Json => {
+                let json = serde_json::to_string_pretty(&report::to_json(&fs));
+                println!("{}", json.expect("Couldn't serialize filesystem"));
+            }
+            Command::Find => {
+                let sizes = fs.sizes();
+                for id in report::find(&fs, &filter) {
+                    println!("{}\t{}", sizes[id.0], fs.path(id));
+                }
+            }
         }
+        return;
     }
-}
-
-fn main() {
-    let mut input = String::new();
-    io::stdin()
-        .lock()
-        .read_to_string(&mut input)
-        .expect("Couldn't read stdin");
 
-    let (_, files) = parse(&input).expect("Failed to parse terminal session");
-
-    let mut directories = HashMap::new();
-    files.iter().for_each(|f| {
-        f.get_directories()
-            .iter()
-            .for_each(|d| *directories.entry(d.to_owned()).or_default() += f.get_size());
+    runner.part(1, "Sum of total sizes of directories <= 100000", || {
+        day07::part1(&fs)
     });
 
-    let total_less_100k: usize = directories.values().filter(|d| *d <= &100_000).sum();
-
-    println!(
-        "Sum of total sizes of directories <= 100000: {}\n",
-        total_less_100k
-    );
-
-    let largest_dir_size = directories
-        .iter()
-        .max_by(|left, right| left.1.cmp(right.1))
-        .expect("No maximum directory found")
-        .1;
-    println!("Largest directory size: {}", largest_dir_size);
+    println!("\nLargest directory size: {}", fs.size(fs.root()));
+    println!("Free space: {}", day07::free_space(&fs));
+    println!("Missing space for upgrade: {}", day07::missing_space(&fs));
 
-    let fs_free_space = 70_000_000 - largest_dir_size;
-    println!("Free space: {}", fs_free_space);
+    runner.part(2, "Smallest deleteable directory", || day07::part2(&fs));
 
-    let missing_space = 30_000_000 - fs_free_space;
-    println!("Missing space for upgrade: {}", missing_space);
-
-    let smallest_dir_size = directories
-        .values()
-        .filter(|v| *v > &missing_space)
-        .min()
-        .expect("Couldn't find the smallest dir");
-    println!("Smallest deleteable directory: {}", smallest_dir_size);
+    if let Some((id, _)) = day07::smallest_deletion(&fs) {
+        println!("Directory to delete: {}", fs.path(id));
+    }
 }
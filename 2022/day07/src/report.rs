@@ -0,0 +1,151 @@
+//! `tree`, `du` and `find` style views of a filesystem, and its JSON export.
+
+use crate::fs::{FileSystem, Node, NodeId};
+use serde_json::{json, Value};
+
+/// A node's name, `/` for the root.
+fn name(fs: &FileSystem, id: NodeId) -> &str {
+    match fs.node(id).name() {
+        "" => "/",
+        name => name,
+    }
+}
+
+/// The puzzle's listing of the whole tree, with the total size of each
+/// directory.
+pub fn tree(fs: &FileSystem) -> String {
+    let sizes = fs.sizes();
+    let mut lines = vec![];
+    let mut pending = vec![(fs.root(), 0)];
+
+    while let Some((id, depth)) = pending.pop() {
+        let name = name(fs, id);
+        let kind = if fs.node(id).is_dir() { "dir" } else { "file" };
+        lines.push(format!(
+            "{}- {name} ({kind}, size={})",
+            "  ".repeat(depth),
+            sizes[id.0]
+        ));
+
+        let children: Vec<NodeId> = fs.children(id).collect();
+        pending.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// The total size of every directory at most `max_depth` levels below the
+/// root, listed after their subdirectories like `du -d`.
+pub fn du(fs: &FileSystem, max_depth: usize) -> String {
+    fn visit(
+        fs: &FileSystem,
+        id: NodeId,
+        depth: usize,
+        max_depth: usize,
+        sizes: &[usize],
+    ) -> String {
+        let mut out: String = fs
+            .children(id)
+            .filter(|&child| depth < max_depth && fs.node(child).is_dir())
+            .map(|child| visit(fs, child, depth + 1, max_depth, sizes))
+            .collect();
+        out += &format!("{}\t{}\n", sizes[id.0], fs.path(id));
+        out
+    }
+
+    visit(fs, fs.root(), 0, max_depth, &fs.sizes())
+}
+
+/// What `find` keeps: nodes of the given kind, with a total size in the given
+/// bounds and a name matching a `*` and `?` pattern.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub directories: Option<bool>,
+    pub min_size: Option<usize>,
+    pub max_size: Option<usize>,
+    pub name: Option<String>,
+}
+
+/// Whether `name` matches a shell pattern where `*` stands for any run of
+/// characters and `?` for any single one.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // matched[j] is whether the pattern read so far matches name[..j].
+    let mut matched = vec![false; name.len() + 1];
+    matched[0] = true;
+
+    for &p in &pattern {
+        let previous = matched.clone();
+        matched[0] = p == '*' && previous[0];
+        for j in 1..=name.len() {
+            matched[j] = match p {
+                '*' => previous[j] || matched[j - 1],
+                '?' => previous[j - 1],
+                c => previous[j - 1] && name[j - 1] == c,
+            };
+        }
+    }
+
+    matched[name.len()]
+}
+
+/// Nodes kept by `filter`, parents before their children.
+pub fn find(fs: &FileSystem, filter: &Filter) -> Vec<NodeId> {
+    let sizes = fs.sizes();
+
+    fs.ids()
+        .filter(|&id| {
+            let node = fs.node(id);
+            let size = sizes[id.0];
+
+            filter.directories.is_none_or(|d| d == node.is_dir())
+                && filter.min_size.is_none_or(|min| size >= min)
+                && filter.max_size.is_none_or(|max| size <= max)
+                && filter
+                    .name
+                    .as_ref()
+                    .is_none_or(|pattern| matches(pattern, node.name()))
+        })
+        .collect()
+}
+
+/// The whole hierarchy as nested objects, directories with their total size
+/// and their entries.
+pub fn to_json(fs: &FileSystem) -> Value {
+    fn visit(fs: &FileSystem, id: NodeId, sizes: &[usize]) -> Value {
+        match fs.node(id) {
+            Node::File(file) => json!({
+                "name": file.name,
+                "type": "file",
+                "size": file.size,
+            }),
+            Node::Dir(_) => json!({
+                "name": name(fs, id),
+                "type": "dir",
+                "size": sizes[id.0],
+                "children": fs
+                    .children(id)
+                    .map(|child| visit(fs, child, sizes))
+                    .collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    visit(fs, fs.root(), &fs.sizes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        assert!(matches("*.txt", "b.txt"));
+        assert!(matches("?", "d"));
+        assert!(matches("a*b*c", "aXbYbc"));
+        assert!(!matches("*.txt", "c.dat"));
+        assert!(!matches("??", "d"));
+    }
+}
@@ -0,0 +1,190 @@
+//! Terminal sessions generated from a filesystem, the inverse of parsing one.
+//!
+//! The traversal is randomized but always valid: every directory gets listed,
+//! its entries come in any order, its listing can happen before or after
+//! visiting some of its subdirectories, some directories are listed twice and
+//! the way back up is sometimes a `$ cd /` followed by the whole path down.
+
+use crate::fs::{FileSystem, Node, NodeId};
+use std::{io, path::Path};
+
+impl FileSystem {
+    /// Reads a directory tree from disk, files with their length in bytes.
+    /// Symbolic links and other special files are left out.
+    pub fn from_disk(path: &Path) -> io::Result<Self> {
+        fn visit(fs: &mut FileSystem, dir: NodeId, path: &Path) -> io::Result<()> {
+            for entry in std::fs::read_dir(path)? {
+                let entry = entry?;
+                let name = entry.file_name().into_string().map_err(|name| {
+                    io::Error::new(io::ErrorKind::InvalidData, format!("{name:?} isn't UTF-8"))
+                })?;
+                if name.contains('\n') {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{name:?} can't be written on one line"),
+                    ));
+                }
+
+                let metadata = entry.metadata()?;
+                if metadata.is_dir() {
+                    let child = fs.add_dir(dir, &name).map_err(io::Error::other)?;
+                    visit(fs, child, &entry.path())?;
+                } else if metadata.is_file() {
+                    fs.add_file(dir, &name, metadata.len() as usize)
+                        .map_err(io::Error::other)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        let mut fs = FileSystem::default();
+        let root = fs.root();
+        visit(&mut fs, root, path)?;
+
+        Ok(fs)
+    }
+}
+
+/// A xorshift generator, so that a seed always gives the same session.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+struct Session<'a> {
+    fs: &'a FileSystem,
+    random: Random,
+    lines: Vec<String>,
+}
+
+impl Session<'_> {
+    fn ls(&mut self, dir: NodeId) {
+        let mut entries: Vec<NodeId> = self.fs.children(dir).collect();
+        self.random.shuffle(&mut entries);
+
+        self.lines.push("$ ls".into());
+        for id in entries {
+            self.lines.push(match self.fs.node(id) {
+                Node::Dir(dir) => format!("dir {}", dir.name),
+                Node::File(file) => format!("{} {}", file.size, file.name),
+            });
+        }
+    }
+
+    /// Lists `dir` and everything below it, starting and ending in `dir`.
+    fn visit(&mut self, dir: NodeId) {
+        let mut subdirs: Vec<NodeId> = self
+            .fs
+            .children(dir)
+            .filter(|&id| self.fs.node(id).is_dir())
+            .collect();
+        self.random.shuffle(&mut subdirs);
+        let listing = self.random.below(subdirs.len() + 1);
+
+        for (i, &subdir) in subdirs.iter().enumerate() {
+            if i == listing {
+                self.ls(dir);
+            }
+
+            self.lines
+                .push(format!("$ cd {}", self.fs.node(subdir).name()));
+            self.visit(subdir);
+
+            if self.random.one_in(6) {
+                self.lines.push("$ cd /".into());
+                let mut path = vec![];
+                let mut current = Some(dir);
+                while let Some(id) = current.filter(|&id| id != self.fs.root()) {
+                    path.push(id);
+                    current = self.fs.node(id).parent();
+                }
+                for id in path.into_iter().rev() {
+                    self.lines.push(format!("$ cd {}", self.fs.node(id).name()));
+                }
+            } else {
+                self.lines.push("$ cd ..".into());
+            }
+        }
+
+        if listing == subdirs.len() || self.random.one_in(8) {
+            self.ls(dir);
+        }
+    }
+}
+
+/// A terminal session listing every directory of `fs`, in an order that
+/// depends on `seed`.
+pub fn transcript(fs: &FileSystem, seed: u64) -> String {
+    let mut session = Session {
+        fs,
+        random: Random(seed.max(1)),
+        lines: vec!["$ cd /".into()],
+    };
+    session.visit(fs.root());
+
+    while session.lines.last().is_some_and(|l| l.starts_with("$ cd")) {
+        session.lines.pop();
+    }
+
+    session.lines.join("\n") + "\n"
+}
+
+/// A terminal session exploring the directory at `path`.
+pub fn from_dir(path: &Path, seed: u64) -> io::Result<String> {
+    Ok(transcript(&FileSystem::from_disk(path)?, seed))
+}
+
+/// Total size of every directory, by path.
+pub fn directory_sizes(fs: &FileSystem) -> Vec<(String, usize)> {
+    let sizes = fs.sizes();
+    let mut directories: Vec<(String, usize)> = fs
+        .directories()
+        .map(|id| (fs.path(id), sizes[id.0]))
+        .collect();
+    directories.sort();
+
+    directories
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn transcripts_parse_back_to_the_same_sizes() {
+        let root = std::env::temp_dir().join(format!("day07-transcript-{}", std::process::id()));
+        fs::create_dir_all(root.join("a/e")).unwrap();
+        fs::create_dir_all(root.join("d/empty")).unwrap();
+        fs::write(root.join("b.txt"), "12345").unwrap();
+        fs::write(root.join("a/f"), "123").unwrap();
+        fs::write(root.join("a/e/i"), "1").unwrap();
+        fs::write(root.join("d/j k"), "1234567").unwrap();
+
+        let disk = FileSystem::from_disk(&root).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(disk.size(disk.root()), 16);
+
+        for seed in 1..50 {
+            let parsed: FileSystem = transcript(&disk, seed).parse().unwrap();
+            assert_eq!(directory_sizes(&parsed), directory_sizes(&disk));
+        }
+    }
+}
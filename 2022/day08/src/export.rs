@@ -0,0 +1,138 @@
+//! Per-tree records as CSV, and pictures of the forest for the terminal.
+
+use crate::{Direction, Forest, Sight};
+
+/// One row per tree with its position, its height, the edges it can be seen
+/// from, its four viewing distances and its scenic score.
+pub fn csv(forest: &Forest, sights: &[Sight]) -> String {
+    let mut header = vec!["x".to_string(), "y".into(), "height".into()];
+    header.extend(Direction::ALL.map(|d| format!("visible_{}", d.name())));
+    header.extend(Direction::ALL.map(|d| format!("distance_{}", d.name())));
+    header.push("scenic_score".into());
+
+    let mut lines = vec![header.join(",")];
+    for (i, sight) in sights.iter().enumerate() {
+        let mut fields = vec![
+            (i % forest.width).to_string(),
+            (i / forest.width).to_string(),
+            forest.tree_map[i].to_string(),
+        ];
+        fields.extend(sight.visible.map(|v| v.to_string()));
+        fields.extend(sight.distance.map(|d| d.to_string()));
+        fields.push(sight.scenic_score().to_string());
+
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// 256-colour palette entries from cold to hot.
+const RAMP: [u8; 16] = [
+    17, 18, 19, 20, 21, 27, 33, 39, 45, 51, 226, 220, 214, 208, 202, 196,
+];
+
+/// The forest with every tree coloured by its scenic score relative to the
+/// best one, using ANSI background colours.
+pub fn heatmap(forest: &Forest, sights: &[Sight]) -> String {
+    let best = sights
+        .iter()
+        .map(Sight::scenic_score)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    sights
+        .chunks(forest.width.max(1))
+        .map(|row| {
+            let cells: String = row
+                .iter()
+                .map(|sight| {
+                    let level = sight.scenic_score() * (RAMP.len() - 1) / best;
+                    format!("\x1b[48;5;{}m  ", RAMP[level])
+                })
+                .collect();
+            cells + "\x1b[0m\n"
+        })
+        .collect()
+}
+
+/// `#` for the trees that can be seen from outside the grid, `.` for the
+/// hidden ones.
+pub fn mask(forest: &Forest, sights: &[Sight]) -> String {
+    sights
+        .chunks(forest.width.max(1))
+        .map(|row| {
+            let cells: String = row
+                .iter()
+                .map(|sight| if sight.is_visible() { '#' } else { '.' })
+                .collect();
+            cells + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forest() -> Forest {
+        include_str!("../input_small.txt").parse().unwrap()
+    }
+
+    #[test]
+    fn csv_round_trips() {
+        let forest = forest();
+        let sights = forest.sights();
+        let csv = csv(&forest, sights);
+        let mut lines = csv.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some(
+                "x,y,height,visible_left,visible_right,visible_up,visible_down,\
+                 distance_left,distance_right,distance_up,distance_down,scenic_score"
+            )
+        );
+
+        let records: Vec<Vec<&str>> = lines.map(|l| l.split(',').collect()).collect();
+        assert_eq!(records.len(), 25);
+        assert_eq!(
+            records[17],
+            ["2", "3", "5", "true", "false", "false", "true", "2", "2", "2", "1", "8"]
+        );
+
+        // The heights, laid back out by position, give the forest again.
+        let mut rows = vec![vec![""; forest.width]; forest.height];
+        for record in &records {
+            let (x, y): (usize, usize) = (record[0].parse().unwrap(), record[1].parse().unwrap());
+            rows[y][x] = record[2];
+        }
+        let text: String = rows.iter().map(|r| r.join(",") + "\n").collect();
+        assert_eq!(text.parse::<Forest>().unwrap().tree_map, forest.tree_map);
+
+        for (record, sight) in records.iter().zip(sights) {
+            let visible: Vec<bool> = record[3..7].iter().map(|v| v.parse().unwrap()).collect();
+            let distance: Vec<usize> = record[7..11].iter().map(|d| d.parse().unwrap()).collect();
+            assert_eq!(visible, sight.visible);
+            assert_eq!(distance, sight.distance);
+            assert_eq!(record[11], sight.scenic_score().to_string());
+        }
+    }
+
+    #[test]
+    fn pictures() {
+        let forest = forest();
+        let sights = forest.sights();
+
+        assert_eq!(mask(&forest, sights), "#####\n###.#\n##.##\n#.#.#\n#####\n");
+
+        let heatmap = heatmap(&forest, sights);
+        let rows: Vec<&str> = heatmap.lines().collect();
+        assert_eq!(rows.len(), 5);
+        assert!(rows.iter().all(|r| r.matches("\x1b[48;5;").count() == 5));
+        assert!(rows.iter().all(|r| r.ends_with("\x1b[0m")));
+        assert!(rows[0].starts_with("\x1b[48;5;17m  "));
+        assert_eq!(rows[3].matches("\x1b[48;5;196m").count(), 1);
+    }
+}
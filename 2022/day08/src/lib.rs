@@ -0,0 +1,269 @@
+//! Day 8: Treetop Tree House.
+
+use std::{cell::OnceCell, str::FromStr};
+
+pub mod export;
+#[cfg(test)]
+mod naive;
+
+pub type Height = u32;
+
+/// Where a tree looks, in the order of the arrays of `Sight`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Up,
+        Direction::Down,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction::Left => "left",
+            Direction::Right => "right",
+            Direction::Up => "up",
+            Direction::Down => "down",
+        }
+    }
+}
+
+/// What a tree sees in each direction: whether it can be seen from that edge
+/// of the grid, and how many trees it sees before its view is blocked.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Sight {
+    pub visible: [bool; 4],
+    pub distance: [usize; 4],
+}
+
+impl Sight {
+    /// Whether the tree can be seen from outside the grid.
+    pub fn is_visible(&self) -> bool {
+        self.visible.iter().any(|&v| v)
+    }
+
+    /// Product of the viewing distances in all four directions.
+    pub fn scenic_score(&self) -> usize {
+        self.distance.iter().product()
+    }
+}
+
+/// Tree heights in row-major order.
+#[derive(Debug)]
+pub struct Forest {
+    pub tree_map: Vec<Height>,
+    pub width: usize,
+    pub height: usize,
+    sights: OnceCell<Vec<Sight>>,
+}
+
+impl FromStr for Forest {
+    type Err = String;
+
+    /// One row per line, either a digit per tree or heights separated by
+    /// commas or spaces, which allows heights above 9. Whitespace around a
+    /// line is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Vec<Height>> = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let l = l.trim();
+                let row: Result<Vec<Height>, String> =
+                    if l.contains(|c: char| c == ',' || c.is_whitespace()) {
+                        l.split(|c: char| c == ',' || c.is_whitespace())
+                            .filter(|t| !t.is_empty())
+                            .map(|t| {
+                                t.parse()
+                                    .map_err(|_| format!("Couldn't parse tree into size: {t:?}"))
+                            })
+                            .collect()
+                    } else {
+                        l.chars()
+                            .map(|c| {
+                                c.to_digit(10)
+                                    .ok_or(format!("Couldn't parse tree into size: {c:?}"))
+                            })
+                            .collect()
+                    };
+
+                row.map_err(|e| format!("Line {}: {e}", i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let width = rows.first().map(Vec::len).unwrap_or_default();
+        let height = rows.len();
+
+        if let Some(i) = rows.iter().position(|r| r.len() != width) {
+            return Err(format!(
+                "Line {}: expected {width} trees, got {}",
+                i + 1,
+                rows[i].len()
+            ));
+        }
+
+        Ok(Self {
+            tree_map: rows.into_iter().flatten().collect(),
+            width,
+            height,
+            sights: OnceCell::new(),
+        })
+    }
+}
+
+impl Forest {
+    /// Sweeps one line of trees, given from the edge a tree looks towards.
+    ///
+    /// The stack holds the trees that can still block the view of the next
+    /// ones, so heights never increase from its bottom to its top. A tree pops
+    /// every shorter one, and whatever is left on top blocks its view.
+    fn sweep(&self, line: impl Iterator<Item = usize>, direction: usize, sights: &mut [Sight]) {
+        let mut stack: Vec<(usize, usize)> = vec![];
+
+        for (position, idx) in line.enumerate() {
+            let tree = self.tree_map[idx];
+            while stack
+                .last()
+                .is_some_and(|&(_, top)| self.tree_map[top] < tree)
+            {
+                stack.pop();
+            }
+
+            let sight = &mut sights[idx];
+            match stack.last() {
+                Some(&(blocker, _)) => sight.distance[direction] = position - blocker,
+                None => {
+                    sight.visible[direction] = true;
+                    sight.distance[direction] = position;
+                }
+            }
+
+            stack.push((position, idx));
+        }
+    }
+
+    /// What every tree sees, in row-major order. The sweeps run the first
+    /// time it is asked for, and every later call shares their result.
+    pub fn sights(&self) -> &[Sight] {
+        self.sights.get_or_init(|| self.sweep_all())
+    }
+
+    /// What every tree sees, with four sweeps over each row and column.
+    fn sweep_all(&self) -> Vec<Sight> {
+        let (width, height) = (self.width, self.height);
+        let mut sights = vec![Sight::default(); self.tree_map.len()];
+
+        for (direction, kind) in Direction::ALL.into_iter().enumerate() {
+            match kind {
+                Direction::Left | Direction::Right => {
+                    for y in 0..height {
+                        let row = (0..width).map(|x| y * width + x);
+                        if kind == Direction::Left {
+                            self.sweep(row, direction, &mut sights);
+                        } else {
+                            self.sweep(row.rev(), direction, &mut sights);
+                        }
+                    }
+                }
+                Direction::Up | Direction::Down => {
+                    for x in 0..width {
+                        let column = (0..height).map(|y| y * width + x);
+                        if kind == Direction::Up {
+                            self.sweep(column, direction, &mut sights);
+                        } else {
+                            self.sweep(column.rev(), direction, &mut sights);
+                        }
+                    }
+                }
+            }
+        }
+
+        sights
+    }
+}
+
+/// Number of trees visible from outside the grid.
+pub fn part1(forest: &Forest) -> usize {
+    forest.sights().iter().filter(|s| s.is_visible()).count()
+}
+
+/// Column and row of the tree with the highest scenic score, the first one in
+/// row-major order on ties, and that score.
+pub fn best_tree(forest: &Forest) -> Option<(usize, usize, usize)> {
+    forest
+        .sights()
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, sight)| sight.scenic_score())
+        .map(|(i, sight)| (i % forest.width, i / forest.width, sight.scenic_score()))
+}
+
+/// Highest scenic score of any tree.
+pub fn part2(forest: &Forest) -> usize {
+    best_tree(forest)
+        .map(|(_, _, score)| score)
+        .expect("Couldn't find a maximum scenic score")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digit_and_separated_rows() {
+        let digits: Forest = "303 \n 255\n653\t".parse().unwrap();
+        assert_eq!(digits.tree_map, [3, 0, 3, 2, 5, 5, 6, 5, 3]);
+        assert_eq!((digits.width, digits.height), (3, 3));
+
+        let separated: Forest = "10, 2,3\n4 5 12 \n".parse().unwrap();
+        assert_eq!(separated.tree_map, [10, 2, 3, 4, 5, 12]);
+
+        assert_eq!(
+            "12\n1a".parse::<Forest>().unwrap_err(),
+            "Line 2: Couldn't parse tree into size: 'a'"
+        );
+        assert_eq!(
+            "12\n123".parse::<Forest>().unwrap_err(),
+            "Line 2: expected 2 trees, got 3"
+        );
+    }
+
+    #[test]
+    fn sweeps_agree_with_ray_scans() {
+        let mut state: u64 = 0x2022_0008;
+        let mut random = |n: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % n
+        };
+
+        for _ in 0..200 {
+            let (width, height) = (1 + random(9) as usize, 1 + random(9) as usize);
+            let tree_map: Vec<Height> = (0..width * height).map(|_| random(13) as Height).collect();
+            let forest = Forest {
+                tree_map,
+                width,
+                height,
+                sights: OnceCell::new(),
+            };
+
+            for (i, sight) in forest.sights().iter().enumerate() {
+                let map = &forest.tree_map;
+                assert_eq!(sight.is_visible(), naive::is_visible(map, i, width, height));
+                assert_eq!(
+                    sight.scenic_score(),
+                    naive::calculate_scenic_score(map, i, width, height)
+                );
+            }
+        }
+    }
+}
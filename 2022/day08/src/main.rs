@@ -1,155 +1,36 @@
-use std::io::{self, BufRead};
+use aoc::runner::Runner;
+use day08::{export, Forest, Sight};
+use std::{env, process};
 
-fn is_visible_left(tree_map: &[i8], idx: usize, width: usize) -> bool {
-    for i in ((idx - (idx % width))..(idx)).rev() {
-        if tree_map[i] >= tree_map[idx] {
-            return false;
-        }
-    }
-
-    true
-}
-
-fn is_visible_right(tree_map: &[i8], idx: usize, width: usize) -> bool {
-    for i in (idx + 1)..(idx + (width - (idx % width))) {
-        if tree_map[i] >= tree_map[idx] {
-            return false;
-        }
-    }
-
-    true
-}
-
-fn is_visible_top(tree_map: &[i8], idx: usize, width: usize) -> bool {
-    let mut i = idx;
-
-    while i >= width {
-        i -= width;
-        if tree_map[i] >= tree_map[idx] {
-            return false;
-        }
-    }
-
-    true
-}
-
-fn is_visible_bottom(tree_map: &[i8], idx: usize, width: usize, height: usize) -> bool {
-    let mut i = idx;
-
-    while i < width * (height - 1) {
-        i += width;
-        if tree_map[i] >= tree_map[idx] {
-            return false;
-        }
-    }
-
-    true
-}
-
-fn is_visible(tree_map: &[i8], idx: usize, width: usize, height: usize) -> bool {
-    is_visible_left(tree_map, idx, width)
-        || is_visible_right(tree_map, idx, width)
-        || is_visible_top(tree_map, idx, width)
-        || is_visible_bottom(tree_map, idx, width, height)
-}
-
-fn scenic_score_left(tree_map: &[i8], idx: usize, width: usize) -> usize {
-    let mut score = 0;
-
-    for i in ((idx - (idx % width))..(idx)).rev() {
-        score += 1;
-        if tree_map[i] >= tree_map[idx] {
-            break;
-        }
-    }
-
-    score
-}
-
-fn scenic_score_right(tree_map: &[i8], idx: usize, width: usize) -> usize {
-    let mut score = 0;
-
-    for i in (idx + 1)..(idx + (width - (idx % width))) {
-        score += 1;
-        if tree_map[i] >= tree_map[idx] {
-            break;
-        }
-    }
-
-    score
+fn usage() -> ! {
+    eprintln!("Usage: day08 [--csv | --heatmap | --mask]");
+    process::exit(1);
 }
 
-fn scenic_score_top(tree_map: &[i8], idx: usize, width: usize) -> usize {
-    let mut i = idx;
-    let mut score = 0;
+fn main() {
+    let mut view = None;
 
-    while i >= width {
-        score += 1;
-        i -= width;
-        if tree_map[i] >= tree_map[idx] {
-            break;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--csv" => view = Some(export::csv as fn(&Forest, &[Sight]) -> String),
+            "--heatmap" => view = Some(export::heatmap),
+            "--mask" => view = Some(export::mask),
+            _ => usage(),
         }
     }
 
-    score
-}
-
-fn scenic_score_bottom(tree_map: &[i8], idx: usize, width: usize, height: usize) -> usize {
-    let mut i = idx;
-    let mut score = 0;
+    let runner = Runner::new(2022, 8);
+    let forest: Forest = aoc::read_stdin().parse().expect("Couldn't parse tree map");
 
-    while i < width * (height - 1) {
-        score += 1;
-        i += width;
-        if tree_map[i] >= tree_map[idx] {
-            break;
-        }
+    if let Some(view) = view {
+        print!("{}", view(&forest, forest.sights()));
+        return;
     }
 
-    score
-}
-
-fn calculate_scenic_score(tree_map: &[i8], idx: usize, width: usize, height: usize) -> usize {
-    scenic_score_left(tree_map, idx, width)
-        * scenic_score_right(tree_map, idx, width)
-        * scenic_score_top(tree_map, idx, width)
-        * scenic_score_bottom(tree_map, idx, width, height)
-}
-
-fn main() {
-    let tree_map: Vec<Vec<i8>> = io::stdin()
-        .lock()
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .chars()
-                .map(|c| c.to_digit(10).expect("Couldn't parse tree into size"))
-                .map(|i| i as i8)
-                .collect::<Vec<i8>>()
-        })
-        .collect();
-
-    let width = tree_map[0].len();
-    let height = tree_map.len();
-    let mut visible_tree_count = width * 2 + height * 2 - 4;
-
-    let tree_map: Vec<i8> = tree_map.into_iter().flatten().collect();
+    runner.part(1, "Visible trees", || day08::part1(&forest));
+    runner.part(2, "Maximum scenic score", || day08::part2(&forest));
 
-    for i in 1..(height - 1) {
-        for j in 1..(width - 1) {
-            if is_visible(&tree_map, (i * width) + j, width, height) {
-                visible_tree_count += 1;
-            }
-        }
+    if let Some((x, y, _)) = day08::best_tree(&forest) {
+        println!("Best tree at x={x}, y={y}");
     }
-
-    let max_scenic_score = tree_map
-        .iter()
-        .enumerate()
-        .map(|(i, _)| calculate_scenic_score(&tree_map, i, width, height))
-        .max()
-        .expect("Couldn't find a maximum scenic score");
-
-    println!("Visible trees: {}", visible_tree_count);
-    println!("Maximum scenic score: {}", max_scenic_score);
 }
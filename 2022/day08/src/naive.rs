@@ -0,0 +1,128 @@
+//! The original ray scans, looking from every tree until the view is blocked.
+//! They take O(width + height) per tree and are kept as a reference for the
+//! sweeps.
+
+use crate::Height;
+
+fn is_visible_left(tree_map: &[Height], idx: usize, width: usize) -> bool {
+    for i in ((idx - (idx % width))..(idx)).rev() {
+        if tree_map[i] >= tree_map[idx] {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_visible_right(tree_map: &[Height], idx: usize, width: usize) -> bool {
+    for i in (idx + 1)..(idx + (width - (idx % width))) {
+        if tree_map[i] >= tree_map[idx] {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_visible_top(tree_map: &[Height], idx: usize, width: usize) -> bool {
+    let mut i = idx;
+
+    while i >= width {
+        i -= width;
+        if tree_map[i] >= tree_map[idx] {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_visible_bottom(tree_map: &[Height], idx: usize, width: usize, height: usize) -> bool {
+    let mut i = idx;
+
+    while i < width * (height - 1) {
+        i += width;
+        if tree_map[i] >= tree_map[idx] {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Whether the tree at `idx` can be seen from outside the grid.
+pub fn is_visible(tree_map: &[Height], idx: usize, width: usize, height: usize) -> bool {
+    is_visible_left(tree_map, idx, width)
+        || is_visible_right(tree_map, idx, width)
+        || is_visible_top(tree_map, idx, width)
+        || is_visible_bottom(tree_map, idx, width, height)
+}
+
+fn scenic_score_left(tree_map: &[Height], idx: usize, width: usize) -> usize {
+    let mut score = 0;
+
+    for i in ((idx - (idx % width))..(idx)).rev() {
+        score += 1;
+        if tree_map[i] >= tree_map[idx] {
+            break;
+        }
+    }
+
+    score
+}
+
+fn scenic_score_right(tree_map: &[Height], idx: usize, width: usize) -> usize {
+    let mut score = 0;
+
+    for i in (idx + 1)..(idx + (width - (idx % width))) {
+        score += 1;
+        if tree_map[i] >= tree_map[idx] {
+            break;
+        }
+    }
+
+    score
+}
+
+fn scenic_score_top(tree_map: &[Height], idx: usize, width: usize) -> usize {
+    let mut i = idx;
+    let mut score = 0;
+
+    while i >= width {
+        score += 1;
+        i -= width;
+        if tree_map[i] >= tree_map[idx] {
+            break;
+        }
+    }
+
+    score
+}
+
+fn scenic_score_bottom(tree_map: &[Height], idx: usize, width: usize, height: usize) -> usize {
+    let mut i = idx;
+    let mut score = 0;
+
+    while i < width * (height - 1) {
+        score += 1;
+        i += width;
+        if tree_map[i] >= tree_map[idx] {
+            break;
+        }
+    }
+
+    score
+}
+
+/// Product of the viewing distances in all four directions from `idx`.
+pub fn calculate_scenic_score(
+    tree_map: &[Height],
+    idx: usize,
+    width: usize,
+    height: usize,
+) -> usize {
+    scenic_score_left(tree_map, idx, width)
+        * scenic_score_right(tree_map, idx, width)
+        * scenic_score_top(tree_map, idx, width)
+        * scenic_score_bottom(tree_map, idx, width, height)
+}
@@ -0,0 +1,331 @@
+//! Day 9: Rope Bridge.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    slice,
+    str::FromStr,
+};
+
+pub mod render;
+pub mod rope;
+
+pub use rope::{Coordinate, Follow, Head, Knot, Rope};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// The head's move for one step, with `y` growing downwards.
+    pub fn delta(&self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "UL" => Direction::UpLeft,
+            "UR" => Direction::UpRight,
+            "DL" => Direction::DownLeft,
+            "DR" => Direction::DownRight,
+            _ => return Err(format!("Unknown direction {s:?}")),
+        })
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Direction::Up => "U",
+            Direction::Down => "D",
+            Direction::Left => "L",
+            Direction::Right => "R",
+            Direction::UpLeft => "UL",
+            Direction::UpRight => "UR",
+            Direction::DownLeft => "DL",
+            Direction::DownRight => "DR",
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Motion {
+    pub direction: Direction,
+    pub count: u32,
+}
+
+impl FromStr for Motion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, count) = s
+            .split_once(' ')
+            .ok_or_else(|| format!("Expected a direction and a count, got {s:?}"))?;
+
+        Ok(Motion {
+            direction: direction.parse()?,
+            count: count
+                .parse()
+                .map_err(|_| format!("Expected a count, got {count:?}"))?,
+        })
+    }
+}
+
+impl Display for Motion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.count)
+    }
+}
+
+/// A motion, or a block of them run a number of times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Motion(Motion),
+    Repeat(usize, Vec<Block>),
+}
+
+/// The series of head motions, one per line.
+///
+/// Lines between `repeat <n> {` and `}` are repeated `n` times, and blocks can
+/// be nested. Blank lines are ignored. Blocks are kept as written and only
+/// unrolled while iterating, so nesting them doesn't multiply memory use.
+pub struct Motions {
+    pub blocks: Vec<Block>,
+}
+
+impl Motions {
+    /// Every motion in order, with repeat blocks unrolled.
+    pub fn iter(&self) -> MotionIter<'_> {
+        MotionIter {
+            stack: vec![(self.blocks.iter(), &self.blocks, 0)],
+        }
+    }
+}
+
+/// Iterator over the motions of [`Motions`].
+pub struct MotionIter<'a> {
+    /// Blocks being run: the rest of the current pass, the whole block and
+    /// how many passes are left after this one.
+    stack: Vec<(slice::Iter<'a, Block>, &'a [Block], usize)>,
+}
+
+impl<'a> Iterator for MotionIter<'a> {
+    type Item = &'a Motion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (pass, blocks, left) = self.stack.last_mut()?;
+
+            match pass.next() {
+                Some(Block::Motion(motion)) => return Some(motion),
+                // Blocks built by hand may run no passes, or have nothing in them.
+                Some(Block::Repeat(0, _)) => {}
+                Some(Block::Repeat(_, inner)) if inner.is_empty() => {}
+                Some(Block::Repeat(count, inner)) => {
+                    self.stack.push((inner.iter(), inner, count - 1));
+                }
+                None if *left > 0 => {
+                    *left -= 1;
+                    *pass = blocks.iter();
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Motions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Open blocks, with the line they start on and their repeat count.
+        let mut blocks: Vec<(usize, usize, Vec<Block>)> = vec![(0, 1, vec![])];
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let error = |e: String| format!("Line {}: {e}", i + 1);
+
+            if line.is_empty() {
+                continue;
+            } else if let Some(count) = line
+                .strip_prefix("repeat ")
+                .and_then(|l| l.strip_suffix('{'))
+            {
+                let count = count
+                    .trim()
+                    .parse()
+                    .map_err(|_| error(format!("Expected a repeat count, got {count:?}")))?;
+                blocks.push((i + 1, count, vec![]));
+            } else if line == "}" {
+                if blocks.len() == 1 {
+                    return Err(error("No repeat block to close".into()));
+                }
+                let (_, count, inner) = blocks.pop().unwrap();
+                // Blocks without motions are dropped, so that every pass over
+                // a block yields at least one motion.
+                if count > 0 && !inner.is_empty() {
+                    blocks
+                        .last_mut()
+                        .unwrap()
+                        .2
+                        .push(Block::Repeat(count, inner));
+                }
+            } else {
+                blocks
+                    .last_mut()
+                    .unwrap()
+                    .2
+                    .push(Block::Motion(line.parse().map_err(error)?));
+            }
+        }
+
+        match blocks.pop() {
+            Some((0, _, blocks)) => Ok(Self { blocks }),
+            Some((line, _, _)) => Err(format!("Line {line}: repeat block is never closed")),
+            None => unreachable!("The outermost block is never popped"),
+        }
+    }
+}
+
+/// Moves `rope` through every motion.
+pub fn simulate<C: Coordinate>(rope: &mut Rope<C>, motions: &Motions) {
+    motions.iter().for_each(|m| rope.apply(m));
+}
+
+/// Number of positions the tail of a rope of `knots` knots visits, 0 for a
+/// rope without knots.
+pub fn tail_locations(motions: &Motions, knots: usize) -> usize {
+    if knots == 0 {
+        return 0;
+    }
+
+    let mut rope = Rope::<i32>::new(knots).track(knots - 1);
+    simulate(&mut rope, motions);
+
+    rope.visited(knots - 1).map_or(0, |v| v.len())
+}
+
+/// Positions visited by the tail of a 2-knot rope.
+pub fn part1(motions: &Motions) -> usize {
+    tail_locations(motions, 2)
+}
+
+/// Positions visited by the tail of a 10-knot rope.
+pub fn part2(motions: &Motions) -> usize {
+    tail_locations(motions, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follow_rules() {
+        let motions: Motions = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2".parse().unwrap();
+
+        let mut wide = Rope::<i64>::new(2).track(0).track(1);
+        simulate(&mut wide, &motions);
+        assert_eq!(wide.visited(1).unwrap().len(), 13);
+        assert!(wide.visited(0).unwrap().len() > 13);
+
+        let mut slack = Rope::<i32>::new(2).with_follow(Follow {
+            slack: 5,
+            diagonal: true,
+        });
+        simulate(&mut slack, &motions);
+        assert_eq!(slack.positions()[1], (0, 0));
+        assert!(slack.visited(1).is_none());
+
+        let mut orthogonal = Rope::<i32>::new(2)
+            .with_follow(Follow {
+                slack: 1,
+                diagonal: false,
+            })
+            .track(1);
+        orthogonal.step(&Direction::Up);
+        orthogonal.step(&Direction::Right);
+        orthogonal.step(&Direction::Up);
+        assert_eq!(orthogonal.positions(), [(1, -2), (0, -1)]);
+
+        assert_eq!(tail_locations(&motions, 0), 0);
+        assert_eq!(tail_locations(&motions, 1), 21);
+    }
+
+    #[test]
+    fn diagonals_and_repeat_blocks() {
+        let motions: Motions = "repeat 2 {\n  UR 2\n  repeat 3 {\n    L 1\n  }\n}\nDR 1"
+            .parse()
+            .unwrap();
+        assert_eq!(motions.iter().count(), 9);
+
+        let mut rope = Rope::<i32>::new(2).track(1);
+        simulate(&mut rope, &motions);
+        assert_eq!(rope.positions(), [(-1, -3), (-1, -4)]);
+
+        let error = |s: &str| s.parse::<Motions>().err().unwrap();
+        assert_eq!(error("R 1\nX 2"), "Line 2: Unknown direction \"X\"");
+        assert_eq!(error("R 1\n}"), "Line 2: No repeat block to close");
+        assert_eq!(
+            error("repeat 2 {\nR 1"),
+            "Line 1: repeat block is never closed"
+        );
+    }
+
+    #[test]
+    fn empty_repeat_blocks_are_skipped() {
+        let up = Motion {
+            direction: Direction::Up,
+            count: 1,
+        };
+        let motions = Motions {
+            blocks: vec![
+                Block::Repeat(0, vec![Block::Motion(up)]),
+                Block::Repeat(usize::MAX, vec![]),
+                Block::Motion(up),
+            ],
+        };
+
+        assert_eq!(motions.iter().collect::<Vec<_>>(), [&up]);
+    }
+
+    #[test]
+    fn repeat_blocks_unroll_lazily() {
+        let motions: Motions = "repeat 1000000 {\nrepeat 1000000 {\nR 1\nU 1\n}\n}\nrepeat 5 {\n}"
+            .parse()
+            .unwrap();
+        let first: Vec<String> = motions.iter().take(3).map(Motion::to_string).collect();
+        assert_eq!(first, ["R 1", "U 1", "R 1"]);
+
+        let motions: Motions = "repeat 0 {\nL 1\n}\nrepeat 2 {\nrepeat 1 {\n}\nD 2\n}"
+            .parse()
+            .unwrap();
+        let all: Vec<String> = motions.iter().map(Motion::to_string).collect();
+        assert_eq!(all, ["D 2", "D 2"]);
+    }
+}
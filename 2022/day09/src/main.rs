@@ -1,145 +1,124 @@
-use std::{
-    collections::HashSet,
-    io::{self, BufRead},
-    str::FromStr,
-};
-
-trait XY {
-    fn coords(&self) -> (i16, i16);
+use aoc::runner::Runner;
+use day09::{render, Coordinate, Follow, Motions, Rope};
+use std::{env, process};
+
+fn usage() -> ! {
+    eprintln!("Usage: day09 [--knots <count>] [--track <knot>]... [--slack <cells>] [--orthogonal] [--wide]");
+    eprintln!("       [--draw <knot> | --ppm <knot> [--scale <pixels>] | --animate]");
+    process::exit(1);
 }
 
-#[derive(Default)]
-struct Head {
-    x: i16,
-    y: i16,
+fn number(arg: Option<&String>) -> usize {
+    arg.and_then(|n| n.parse().ok()).unwrap_or_else(|| usage())
 }
 
-impl Head {
-    fn up(&mut self) {
-        self.y -= 1;
-    }
-
-    fn down(&mut self) {
-        self.y += 1;
-    }
+enum Picture {
+    Ascii(usize),
+    Ppm(usize),
+    Animation,
+}
 
-    fn left(&mut self) {
-        self.x -= 1;
-    }
+/// Runs a custom rope, then either draws `picture` or prints how many
+/// positions each tracked knot visited.
+fn custom<C: Coordinate>(
+    motions: &Motions,
+    knots: usize,
+    tracked: &[usize],
+    follow: Follow,
+    picture: Option<Picture>,
+    scale: usize,
+) {
+    let start = tracked
+        .iter()
+        .fold(Rope::<C>::new(knots).with_follow(follow), |rope, &k| {
+            rope.track(k)
+        });
 
-    fn right(&mut self) {
-        self.x += 1;
+    if let Some(Picture::Animation) = picture {
+        print!("{}", render::animate(&start, motions));
+        return;
     }
 
-    fn move_towards(&mut self, dir: &Direction) -> (i16, i16) {
-        match dir {
-            Direction::Up => self.up(),
-            Direction::Down => self.down(),
-            Direction::Left => self.left(),
-            Direction::Right => self.right(),
+    let mut rope = start;
+    day09::simulate(&mut rope, motions);
+
+    match picture {
+        Some(Picture::Ascii(knot)) => print!("{}", render::ascii(rope.visited(knot).unwrap())),
+        Some(Picture::Ppm(knot)) => print!("{}", render::ppm(rope.visited(knot).unwrap(), scale)),
+        _ => {
+            for &knot in tracked {
+                let visited = rope.visited(knot).map_or(0, |v| v.len());
+                println!(
+                    "Number of unique locations for knot {knot} of {knots}-knot rope: {visited}"
+                );
+            }
         }
-
-        (self.x, self.y)
     }
 }
 
-#[derive(Default, Clone)]
-struct Knot {
-    x: i16,
-    y: i16,
-    coords: HashSet<(i16, i16)>,
-}
-
-impl Knot {
-    fn move_needed(&self, coords: (i16, i16)) -> bool {
-        (coords.0 - self.x).abs() == 2 || (coords.1 - self.y).abs() == 2
-    }
-
-    fn move_towards(&mut self, dest: (i16, i16)) -> (i16, i16) {
-        if self.move_needed(dest) {
-            match dest.0 - self.x {
-                2 | 1 => self.x += 1,
-                -2 | -1 => self.x -= 1,
-                _ => {}
-            };
-            match dest.1 - self.y {
-                2 | 1 => self.y += 1,
-                -2 | -1 => self.y -= 1,
-                _ => {}
-            };
+fn main() {
+    let mut knots = None;
+    let mut tracked = vec![];
+    let mut follow = Follow::default();
+    let mut wide = false;
+    let mut picture = None;
+    let mut scale = 4;
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--knots" => knots = Some(number(args.next())),
+            "--track" => tracked.push(number(args.next())),
+            "--slack" => follow.slack = number(args.next()) as u32,
+            "--orthogonal" => follow.diagonal = false,
+            "--wide" => wide = true,
+            "--draw" => picture = Some(Picture::Ascii(number(args.next()))),
+            "--ppm" => picture = Some(Picture::Ppm(number(args.next()))),
+            "--scale" => scale = number(args.next()),
+            "--animate" => picture = Some(Picture::Animation),
+            _ => usage(),
         }
-
-        self.coords.insert((self.x, self.y));
-
-        (self.x, self.y)
-    }
-
-    fn total_unique_locations(&self) -> usize {
-        self.coords.len()
     }
-}
 
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-struct Motion {
-    pub direction: Direction,
-    pub count: i16,
-}
-
-impl FromStr for Motion {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(' ').collect();
+    let runner = Runner::new(2022, 9);
+    let motions: Motions = aoc::read_stdin().parse().expect("Couldn't parse motions");
 
-        let count = parts[1].parse().unwrap();
-
-        let direction = match parts[0] {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => unreachable!(),
-        };
-
-        Ok(Motion { direction, count })
+    if picture.is_none() {
+        runner.part(1, "Number of unique tail locations for 2-knot rope", || {
+            day09::part1(&motions)
+        });
+        runner.part(
+            2,
+            "Number of unique tail locations for 10-knot rope",
+            || day09::part2(&motions),
+        );
     }
-}
 
-fn main() {
-    let mut head: Head = Default::default();
-    let mut knots: Vec<Knot> = vec![Default::default(); 9];
-
-    io::stdin()
-        .lock()
-        .lines()
-        .map(|l| {
-            l.expect("Couldn't read from stdin")
-                .parse()
-                .expect("Couldn't parse line as Motion")
-        })
-        .for_each(|m: Motion| {
-            for _ in 0..m.count {
-                let mut coords = head.move_towards(&m.direction);
-                coords = knots[0].move_towards(coords);
-                knots
-                    .iter_mut()
-                    .skip(1)
-                    .for_each(|k| coords = k.move_towards(coords));
-            }
-        });
+    if knots.is_some()
+        || !tracked.is_empty()
+        || follow != Follow::default()
+        || wide
+        || picture.is_some()
+    {
+        let knots = knots.unwrap_or(10);
+        if let Some(Picture::Ascii(knot) | Picture::Ppm(knot)) = picture {
+            tracked.push(knot);
+        }
+        if knots == 0 || tracked.iter().any(|&k| k >= knots) {
+            usage();
+        }
+        if tracked.is_empty() {
+            tracked.push(knots - 1);
+        }
 
-    println!(
-        "Number of unique tail locations for 2-knot rope: {}",
-        knots[0].total_unique_locations()
-    );
-    println!(
-        "Number of unique tail locations for 10-knot rope: {}",
-        knots[8].total_unique_locations()
-    );
+        if picture.is_none() {
+            println!();
+        }
+        if wide {
+            custom::<i64>(&motions, knots, &tracked, follow, picture, scale);
+        } else {
+            custom::<i32>(&motions, knots, &tracked, follow, picture, scale);
+        }
+    }
 }
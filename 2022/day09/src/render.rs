@@ -0,0 +1,211 @@
+//! Pictures of the rope and of the cells its knots visited, drawn like the
+//! puzzle's diagrams: `y` grows downwards and the starting cell is `s`.
+
+use crate::{rope::Coordinate, Motions, Rope};
+use std::collections::HashSet;
+
+/// The smallest rectangle holding a set of cells, inclusive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub max_x: i64,
+    pub min_y: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    /// The rectangle around the origin and every cell of `cells`.
+    pub fn around<C: Coordinate>(cells: impl IntoIterator<Item = (C, C)>) -> Self {
+        cells.into_iter().fold(
+            Bounds {
+                min_x: 0,
+                max_x: 0,
+                min_y: 0,
+                max_y: 0,
+            },
+            |b, (x, y)| Bounds {
+                min_x: b.min_x.min(x.to_i64()),
+                max_x: b.max_x.max(x.to_i64()),
+                min_y: b.min_y.min(y.to_i64()),
+                max_y: b.max_y.max(y.to_i64()),
+            },
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y + 1) as usize
+    }
+
+    /// Rows of cells, each cell drawn by `cell` from its position.
+    fn draw(&self, cell: impl Fn(i64, i64) -> char) -> String {
+        (self.min_y..=self.max_y)
+            .map(|y| {
+                let row: String = (self.min_x..=self.max_x).map(|x| cell(x, y)).collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+fn cells<C: Coordinate>(visited: &HashSet<(C, C)>) -> HashSet<(i64, i64)> {
+    visited
+        .iter()
+        .map(|&(x, y)| (x.to_i64(), y.to_i64()))
+        .collect()
+}
+
+/// The visited cells as `#` on a background of `.`, the origin as `s`.
+pub fn ascii<C: Coordinate>(visited: &HashSet<(C, C)>) -> String {
+    let cells = cells(visited);
+
+    Bounds::around(visited.iter().copied()).draw(|x, y| match (x, y) {
+        (0, 0) => 's',
+        _ if cells.contains(&(x, y)) => '#',
+        _ => '.',
+    })
+}
+
+/// The visited cells as a plain PPM image, `scale` pixels per cell: visited
+/// cells in white, the origin in red.
+pub fn ppm<C: Coordinate>(visited: &HashSet<(C, C)>, scale: usize) -> String {
+    let cells = cells(visited);
+    let bounds = Bounds::around(visited.iter().copied());
+    let scale = scale.max(1);
+
+    let mut out = format!(
+        "P3\n{} {}\n255\n",
+        bounds.width() * scale,
+        bounds.height() * scale
+    );
+    for y in bounds.min_y..=bounds.max_y {
+        let row: Vec<&str> = (bounds.min_x..=bounds.max_x)
+            .flat_map(|x| {
+                let pixel = match (x, y) {
+                    (0, 0) => "255 0 0",
+                    _ if cells.contains(&(x, y)) => "255 255 255",
+                    _ => "0 0 0",
+                };
+                std::iter::repeat_n(pixel, scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            out += &row.join(" ");
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// The label of knot `i`: `H` for the head, then digits and letters.
+fn label(i: usize) -> char {
+    match i {
+        0 => 'H',
+        1..=9 => (b'0' + i as u8) as char,
+        10..=35 => (b'a' + (i - 10) as u8) as char,
+        _ => '*',
+    }
+}
+
+/// Every knot of a rope within `bounds`, knots closer to the head drawn over
+/// the ones behind them, and the origin as `s` when no knot covers it.
+pub fn frame<C: Coordinate>(positions: &[(C, C)], bounds: &Bounds) -> String {
+    bounds.draw(|x, y| {
+        positions
+            .iter()
+            .position(|&(kx, ky)| (kx.to_i64(), ky.to_i64()) == (x, y))
+            .map(label)
+            .unwrap_or(if (x, y) == (0, 0) { 's' } else { '.' })
+    })
+}
+
+/// The rope after each step of every motion, each motion introduced by a
+/// `== <motion> ==` line, all frames sized to fit every position the knots
+/// go through.
+pub fn animate<C: Coordinate>(rope: &Rope<C>, motions: &Motions) -> String {
+    let mut trial = rope.clone();
+    let mut cells = trial.positions();
+    for motion in motions.iter() {
+        for _ in 0..motion.count {
+            trial.step(&motion.direction);
+            cells.extend(trial.positions());
+        }
+    }
+    let bounds = Bounds::around(cells);
+
+    let mut rope = rope.clone();
+    let mut out = frame(&rope.positions(), &bounds);
+    for motion in motions.iter() {
+        out += &format!("\n== {motion} ==\n");
+        for _ in 0..motion.count {
+            rope.step(&motion.direction);
+            out += "\n";
+            out += &frame(&rope.positions(), &bounds);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visited() -> HashSet<(i32, i32)> {
+        [(0, 0), (1, 0), (2, -1), (-1, 1)].into_iter().collect()
+    }
+
+    #[test]
+    fn ascii_grid() {
+        assert_eq!(ascii(&visited()), "...#\n.s#.\n#...\n");
+        assert_eq!(ascii(&HashSet::<(i64, i64)>::new()), "s\n");
+    }
+
+    #[test]
+    fn ppm_image() {
+        let image = ppm(&visited(), 2);
+        let lines: Vec<&str> = image.lines().collect();
+
+        assert_eq!(lines[..3], ["P3", "8 6", "255"]);
+        assert_eq!(lines.len(), 3 + 6);
+        assert!(lines[3..].iter().all(|l| l.split(' ').count() == 8 * 3));
+        assert_eq!(lines[5], lines[6]);
+        assert_eq!(
+            lines[5],
+            "0 0 0 0 0 0 255 0 0 255 0 0 255 255 255 255 255 255 0 0 0 0 0 0"
+        );
+
+        assert_eq!(ppm(&visited(), 0), ppm(&visited(), 1));
+    }
+
+    #[test]
+    fn animation_frames() {
+        let motions: Motions = "R 2\nU 1".parse().unwrap();
+        let rope = Rope::<i32>::new(3);
+
+        assert_eq!(
+            animate(&rope, &motions),
+            "\
+...
+H..
+
+== R 2 ==
+
+...
+1H.
+
+...
+21H
+
+== U 1 ==
+
+..H
+21.
+"
+        );
+    }
+}
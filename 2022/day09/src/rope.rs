@@ -0,0 +1,210 @@
+//! A rope of any number of knots, each following the one ahead of it.
+
+use crate::{Direction, Motion};
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+/// Integer type the knot positions are stored in.
+pub trait Coordinate:
+    Copy + Debug + Default + Eq + Ord + Hash + Add<Output = Self> + Sub<Output = Self>
+{
+    fn from_i32(n: i32) -> Self;
+    fn to_i64(self) -> i64;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn from_i32(n: i32) -> Self {
+                n as $t
+            }
+
+            fn to_i64(self) -> i64 {
+                self as i64
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        })*
+    };
+}
+
+coordinate!(i32, i64);
+
+/// How a knot catches up with the one ahead of it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Follow {
+    /// How far apart, along either axis, two knots can be before the second
+    /// one moves.
+    pub slack: u32,
+    /// Whether a knot can move diagonally, or only along one axis at a time.
+    pub diagonal: bool,
+}
+
+impl Default for Follow {
+    fn default() -> Self {
+        Self {
+            slack: 1,
+            diagonal: true,
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct Head<C = i32> {
+    pub x: C,
+    pub y: C,
+    pub tracked: bool,
+    pub coords: HashSet<(C, C)>,
+}
+
+impl<C: Coordinate> Head<C> {
+    pub fn move_towards(&mut self, dir: &Direction) -> (C, C) {
+        let (dx, dy) = dir.delta();
+        self.x = self.x + C::from_i32(dx);
+        self.y = self.y + C::from_i32(dy);
+
+        if self.tracked {
+            self.coords.insert((self.x, self.y));
+        }
+
+        (self.x, self.y)
+    }
+}
+
+/// A knot following the one ahead of it, with every position it visited if it
+/// is tracked.
+#[derive(Default, Clone)]
+pub struct Knot<C = i32> {
+    pub x: C,
+    pub y: C,
+    pub tracked: bool,
+    pub coords: HashSet<(C, C)>,
+}
+
+impl<C: Coordinate> Knot<C> {
+    fn move_needed(&self, coords: (C, C), follow: &Follow) -> bool {
+        let slack = C::from_i32(follow.slack as i32);
+
+        (coords.0 - self.x).abs() > slack || (coords.1 - self.y).abs() > slack
+    }
+
+    /// Catches up with the knot at `dest`, one cell at a time.
+    pub fn move_towards(&mut self, dest: (C, C), follow: &Follow) -> (C, C) {
+        while self.move_needed(dest, follow) {
+            let (dx, dy) = (dest.0 - self.x, dest.1 - self.y);
+
+            if follow.diagonal {
+                self.x = self.x + dx.signum();
+                self.y = self.y + dy.signum();
+            } else if dx.abs() >= dy.abs() {
+                self.x = self.x + dx.signum();
+            } else {
+                self.y = self.y + dy.signum();
+            }
+
+            if self.tracked {
+                self.coords.insert((self.x, self.y));
+            }
+        }
+
+        (self.x, self.y)
+    }
+
+    pub fn total_unique_locations(&self) -> usize {
+        self.coords.len()
+    }
+}
+
+/// A head and the knots behind it, all starting at the origin. Knots are
+/// numbered from the head, which is knot 0, to the tail.
+#[derive(Clone)]
+pub struct Rope<C = i32> {
+    pub head: Head<C>,
+    pub knots: Vec<Knot<C>>,
+    pub follow: Follow,
+}
+
+impl<C: Coordinate> Rope<C> {
+    /// A rope of `knots` knots, head included, with none of them tracked.
+    pub fn new(knots: usize) -> Self {
+        assert!(knots > 0, "A rope needs at least a head");
+
+        Self {
+            head: Head::default(),
+            knots: vec![Knot::default(); knots - 1],
+            follow: Follow::default(),
+        }
+    }
+
+    pub fn with_follow(mut self, follow: Follow) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Starts recording the positions visited by knot `knot`, the origin
+    /// included.
+    pub fn track(mut self, knot: usize) -> Self {
+        let origin = (C::default(), C::default());
+
+        match knot {
+            0 => {
+                self.head.tracked = true;
+                self.head.coords.insert(origin);
+            }
+            _ => {
+                let knot = &mut self.knots[knot - 1];
+                knot.tracked = true;
+                knot.coords.insert(origin);
+            }
+        }
+
+        self
+    }
+
+    /// Moves the head one cell and lets every knot catch up.
+    pub fn step(&mut self, direction: &Direction) {
+        let mut coords = self.head.move_towards(direction);
+        for knot in &mut self.knots {
+            coords = knot.move_towards(coords, &self.follow);
+        }
+    }
+
+    pub fn apply(&mut self, motion: &Motion) {
+        for _ in 0..motion.count {
+            self.step(&motion.direction);
+        }
+    }
+
+    /// Current position of every knot, head first.
+    pub fn positions(&self) -> Vec<(C, C)> {
+        [(self.head.x, self.head.y)]
+            .into_iter()
+            .chain(self.knots.iter().map(|k| (k.x, k.y)))
+            .collect()
+    }
+
+    /// Positions visited by knot `knot`, if it is tracked.
+    pub fn visited(&self, knot: usize) -> Option<&HashSet<(C, C)>> {
+        let (tracked, coords) = match knot {
+            0 => (self.head.tracked, &self.head.coords),
+            _ => {
+                let knot = self.knots.get(knot - 1)?;
+                (knot.tracked, &knot.coords)
+            }
+        };
+
+        tracked.then_some(coords)
+    }
+}
@@ -0,0 +1,262 @@
+//! The instruction set: every opcode with its operands, how many cycles it
+//! takes and what it does once those cycles are over.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Registers `a` to `z`; `x` holds the sprite position and starts at 1, the
+/// others start at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registers(pub [i64; 26]);
+
+impl Default for Registers {
+    fn default() -> Self {
+        let mut registers = Registers([0; 26]);
+        registers.set(X, 1);
+        registers
+    }
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> i64 {
+        self.0[register.0 as usize]
+    }
+
+    pub fn set(&mut self, register: Register, value: i64) {
+        self.0[register.0 as usize] = value;
+    }
+}
+
+/// A register, by its letter's offset from `a`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Register(pub u8);
+
+pub const X: Register = Register(b'x' - b'a');
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", (b'a' + self.0) as char)
+    }
+}
+
+/// An instruction argument, read from a register or given as is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+impl Operand {
+    pub fn get(&self, registers: &Registers) -> i64 {
+        match self {
+            Operand::Register(r) => registers.get(*r),
+            Operand::Value(v) => *v,
+        }
+    }
+
+    fn register(&self) -> Register {
+        match self {
+            Operand::Register(r) => *r,
+            Operand::Value(_) => unreachable!("Operand kinds are checked when parsing"),
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[c] if c.is_ascii_lowercase() => Ok(Operand::Register(Register(c - b'a'))),
+            _ => s
+                .parse()
+                .map(Operand::Value)
+                .map_err(|_| format!("Expected a register or an integer, got {s:?}")),
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(r) => write!(f, "{r}"),
+            Operand::Value(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+/// What an operand of an opcode can be.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Register,
+    Value,
+}
+
+/// Where execution goes once an instruction is done.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    /// Moves by this many instructions from the current one.
+    Jump(i64),
+}
+
+pub struct Opcode {
+    pub name: &'static str,
+    pub operands: &'static [Kind],
+    pub cycles: u32,
+    /// What the instruction does, `None` when its arithmetic overflows.
+    pub effect: fn(&mut Registers, &[Operand]) -> Option<Flow>,
+    pub summary: &'static str,
+}
+
+/// Every opcode the CPU knows.
+pub const INSTRUCTION_SET: &[Opcode] = &[
+    Opcode {
+        name: "noop",
+        operands: &[],
+        cycles: 1,
+        effect: |_, _| Some(Flow::Next),
+        summary: "does nothing",
+    },
+    Opcode {
+        name: "addx",
+        operands: &[Kind::Value],
+        cycles: 2,
+        effect: |r, o| {
+            r.set(X, r.get(X).checked_add(o[0].get(r))?);
+            Some(Flow::Next)
+        },
+        summary: "x += v",
+    },
+    Opcode {
+        name: "mulx",
+        operands: &[Kind::Value],
+        cycles: 2,
+        effect: |r, o| {
+            r.set(X, r.get(X).checked_mul(o[0].get(r))?);
+            Some(Flow::Next)
+        },
+        summary: "x *= v",
+    },
+    Opcode {
+        name: "set",
+        operands: &[Kind::Register, Kind::Value],
+        cycles: 1,
+        effect: |r, o| {
+            r.set(o[0].register(), o[1].get(r));
+            Some(Flow::Next)
+        },
+        summary: "r = v",
+    },
+    Opcode {
+        name: "add",
+        operands: &[Kind::Register, Kind::Value],
+        cycles: 2,
+        effect: |r, o| {
+            let register = o[0].register();
+            r.set(register, r.get(register).checked_add(o[1].get(r))?);
+            Some(Flow::Next)
+        },
+        summary: "r += v",
+    },
+    Opcode {
+        name: "mul",
+        operands: &[Kind::Register, Kind::Value],
+        cycles: 2,
+        effect: |r, o| {
+            let register = o[0].register();
+            r.set(register, r.get(register).checked_mul(o[1].get(r))?);
+            Some(Flow::Next)
+        },
+        summary: "r *= v",
+    },
+    Opcode {
+        name: "jmp",
+        operands: &[Kind::Value],
+        cycles: 1,
+        effect: |r, o| Some(Flow::Jump(o[0].get(r))),
+        summary: "jump by v instructions",
+    },
+    Opcode {
+        name: "skz",
+        operands: &[Kind::Value],
+        cycles: 1,
+        effect: |r, o| match o[0].get(r) {
+            0 => Some(Flow::Jump(2)),
+            _ => Some(Flow::Next),
+        },
+        summary: "skip the next instruction if v is 0",
+    },
+    Opcode {
+        name: "sknz",
+        operands: &[Kind::Value],
+        cycles: 1,
+        effect: |r, o| match o[0].get(r) {
+            0 => Some(Flow::Next),
+            _ => Some(Flow::Jump(2)),
+        },
+        summary: "skip the next instruction unless v is 0",
+    },
+];
+
+#[derive(Clone)]
+pub struct Instruction {
+    pub opcode: &'static Opcode,
+    pub operands: Vec<Operand>,
+}
+
+impl Instruction {
+    /// Carries out the instruction, once its cycles are over, or `None` if
+    /// its arithmetic overflows.
+    pub fn execute(&self, registers: &mut Registers) -> Option<Flow> {
+        (self.opcode.effect)(registers, &self.operands)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let name = words.next().ok_or("Expected an instruction")?;
+        let opcode = INSTRUCTION_SET
+            .iter()
+            .find(|o| o.name == name)
+            .ok_or_else(|| format!("Unknown opcode {name:?}"))?;
+
+        let operands: Vec<Operand> = words.map(str::parse).collect::<Result<_, _>>()?;
+        if operands.len() != opcode.operands.len() {
+            return Err(format!(
+                "{name} takes {} operands, got {}",
+                opcode.operands.len(),
+                operands.len()
+            ));
+        }
+        for (operand, kind) in operands.iter().zip(opcode.operands) {
+            if let (Operand::Value(v), Kind::Register) = (operand, kind) {
+                return Err(format!("{name} expects a register, got {v}"));
+            }
+        }
+
+        Ok(Instruction { opcode, operands })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.opcode.name)?;
+        for operand in &self.operands {
+            write!(f, " {operand}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
@@ -0,0 +1,360 @@
+//! Day 10: Cathode-Ray Tube.
+
+use aoc::{Answer, Image};
+use std::{fmt::Display, str::FromStr};
+
+pub mod isa;
+pub mod ocr;
+
+pub use isa::{Flow, Instruction, Registers, INSTRUCTION_SET, X};
+pub use ocr::OcrError;
+
+/// Cycles after which a program that is still running is given up on.
+const CYCLE_LIMIT: i64 = 1_000_000;
+
+/// A 40-pixel wide screen drawn one pixel per CPU cycle.
+pub struct Crt {
+    pub lines: Vec<Vec<bool>>,
+    pos: (usize, usize),
+}
+
+impl Crt {
+    pub fn sync(&mut self, x: i16) {
+        if let Ok(x) = x.try_into() {
+            self.lines[self.pos.0].push(self.pos.1 >= x && self.pos.1 <= x + 2);
+        } else {
+            self.lines[self.pos.0].push(false);
+        }
+
+        self.pos = (self.pos.0, self.pos.1 + 1);
+
+        if self.pos.1 == 40 {
+            self.lines.push(vec![]);
+            self.pos.0 += 1;
+            self.pos.1 = 0;
+        }
+    }
+
+    pub fn new() -> Self {
+        Self {
+            lines: vec![vec![]],
+            pos: (0, 0),
+        }
+    }
+
+    /// The letters drawn on the screen.
+    pub fn text(&self) -> Result<String, OcrError> {
+        ocr::read(&self.lines)
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            f.write_str(
+                &line
+                    .iter()
+                    .map(|b| if *b { '#' } else { '.' })
+                    .collect::<String>(),
+            )
+            .unwrap();
+            f.write_str("\n").unwrap();
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Cpu<'a> {
+    instructions: Vec<Instruction>,
+    pc: usize,
+    pub registers: Registers,
+    cycle: i64,
+    breakpoints: [i64; 6],
+    signal_strengths: Vec<i64>,
+    crt: &'a mut Crt,
+}
+
+impl<'a> Cpu<'a> {
+    /// One cycle, which fails when the signal strength overflows.
+    fn tick(&mut self) -> Result<(), String> {
+        self.cycle += 1;
+
+        let x = self.registers.get(X);
+        self.crt
+            .sync(i16::try_from(x.saturating_sub(1)).unwrap_or(i16::MIN));
+
+        if self.breakpoints.contains(&self.cycle) {
+            let strength = self
+                .cycle
+                .checked_mul(x)
+                .filter(|s| self.sss().checked_add(*s).is_some())
+                .ok_or_else(|| {
+                    format!(
+                        "Signal strength overflows during cycle {}, at address {}",
+                        self.cycle, self.pc
+                    )
+                })?;
+            self.signal_strengths.push(strength);
+        }
+
+        Ok(())
+    }
+
+    /// Runs until execution leaves the program. Each instruction takes effect
+    /// once all of its cycles are over. Fails if the program doesn't halt
+    /// within the cycle limit or its arithmetic overflows.
+    pub fn run(&mut self) -> Result<(), String> {
+        while let Some(instruction) = self.instructions.get(self.pc).cloned() {
+            if self.cycle >= CYCLE_LIMIT {
+                return Err(format!(
+                    "Doesn't halt: still running after {CYCLE_LIMIT} cycles, at address {}",
+                    self.pc
+                ));
+            }
+
+            for _ in 0..instruction.opcode.cycles {
+                self.tick()?;
+            }
+
+            let offset = match instruction.execute(&mut self.registers) {
+                Some(Flow::Next) => 1,
+                Some(Flow::Jump(offset)) => offset,
+                None => {
+                    return Err(format!(
+                        "Arithmetic overflow in {instruction}, at address {}",
+                        self.pc
+                    ))
+                }
+            };
+            match (self.pc as i64).checked_add(offset) {
+                Some(pc) if pc >= 0 => self.pc = pc as usize,
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sum of the signal strengths sampled at the breakpoints.
+    pub fn sss(&self) -> i64 {
+        self.signal_strengths.iter().sum()
+    }
+
+    pub fn new(instructions: Vec<Instruction>, crt: &'a mut Crt) -> Cpu<'a> {
+        Self {
+            instructions,
+            pc: 0,
+            registers: Registers::default(),
+            cycle: 0,
+            breakpoints: [20, 60, 100, 140, 180, 220],
+            signal_strengths: vec![],
+            crt,
+        }
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&Crt> for Image {
+    fn from(crt: &Crt) -> Self {
+        Image {
+            rows: crt
+                .lines
+                .iter()
+                .filter(|l| !l.is_empty())
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// The CPU program, one instruction per line.
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
+
+impl FromStr for Program {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let instructions = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| l.parse().map_err(|e| format!("Line {}: {e}", i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { instructions })
+    }
+}
+
+impl Display for Program {
+    /// The program's source, one instruction per line.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for instruction in &self.instructions {
+            writeln!(f, "{instruction}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Program {
+    /// A listing of the program with the address, cost and effect of every
+    /// instruction, and where constant jumps and skips lead.
+    pub fn disassemble(&self) -> String {
+        let width = self.instructions.len().to_string().len();
+        let source_width = self
+            .instructions
+            .iter()
+            .map(|i| i.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(address, instruction)| {
+                let opcode = instruction.opcode;
+                let plural = if opcode.cycles == 1 { "" } else { "s" };
+                let mut line = format!(
+                    "{address:>width$}  {:<source_width$}  ; {} cycle{plural}, {}",
+                    instruction.to_string(),
+                    opcode.cycles,
+                    opcode.summary
+                );
+
+                let target = match (opcode.name, instruction.operands.first()) {
+                    ("jmp", Some(isa::Operand::Value(offset))) => {
+                        (address as i64).checked_add(*offset)
+                    }
+                    ("skz" | "sknz", _) => Some(address as i64 + 2),
+                    _ => None,
+                };
+                if let Some(target) = target {
+                    line += &format!(" (to {target})");
+                }
+
+                line + "\n"
+            })
+            .collect()
+    }
+}
+
+fn run(program: &Program) -> Result<(i64, Crt), String> {
+    let mut crt = Crt::new();
+    let mut cpu = Cpu::new(program.instructions.clone(), &mut crt);
+
+    cpu.run()?;
+    let sss = cpu.sss();
+
+    Ok((sss, crt))
+}
+
+/// Sum of the six sampled signal strengths.
+pub fn part1(program: &Program) -> Result<i64, String> {
+    Ok(run(program)?.0)
+}
+
+/// What the CRT shows: its image, and the letters read from it.
+#[derive(Clone)]
+pub struct Screen {
+    pub image: Image,
+    pub text: Result<String, OcrError>,
+}
+
+impl Display for Screen {
+    /// The letters when they could all be read, the image otherwise.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.text {
+            Ok(text) => f.write_str(text),
+            Err(_) => write!(f, "{}", self.image),
+        }
+    }
+}
+
+impl From<Screen> for Answer {
+    fn from(screen: Screen) -> Self {
+        match screen.text {
+            Ok(text) => Answer::Text(text),
+            Err(_) => Answer::Image(screen.image),
+        }
+    }
+}
+
+/// The screen drawn on the CRT.
+pub fn part2(program: &Program) -> Result<Screen, String> {
+    let crt = run(program)?.1;
+
+    Ok(Screen {
+        image: Image::from(&crt),
+        text: crt.text(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_opcodes_and_registers() {
+        let program: Program = "set c 3\nmulx 2\nadd c -1\nskz c\njmp -3\nset y x\naddx y"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            program.to_string().parse::<Program>().unwrap().to_string(),
+            program.to_string()
+        );
+
+        let mut crt = Crt::new();
+        let mut cpu = Cpu::new(program.instructions, &mut crt);
+        cpu.run().unwrap();
+
+        assert_eq!(cpu.registers.get(X), 16);
+        assert_eq!(cpu.registers.get(isa::Register(b'y' - b'a')), 8);
+        assert_eq!(cpu.cycle, 1 + 2 * (2 + 2 + 1 + 1) + (2 + 2 + 1) + 1 + 2);
+
+        let error = |s: &str| s.parse::<Program>().err().unwrap();
+        assert_eq!(
+            error("noop\nset 3 4"),
+            "Line 2: set expects a register, got 3"
+        );
+        assert_eq!(error("addx"), "Line 1: addx takes 1 operands, got 0");
+    }
+
+    #[test]
+    fn runaway_programs_are_errors() {
+        let program: Program = "set x 4611686018427387904\nmulx 2".parse().unwrap();
+        assert_eq!(
+            part1(&program).unwrap_err(),
+            "Arithmetic overflow in mulx 2, at address 1"
+        );
+
+        let program: Program = "noop\njmp 0".parse().unwrap();
+        assert_eq!(
+            part2(&program).err().unwrap(),
+            format!("Doesn't halt: still running after {CYCLE_LIMIT} cycles, at address 1")
+        );
+        assert!(program
+            .disassemble()
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("1  jmp 0"));
+
+        let program: Program = "set x 9223372036854775807\nnoop\nnoop\nnoop"
+            .parse()
+            .unwrap();
+        let mut crt = Crt::new();
+        let mut cpu = Cpu::new(program.instructions, &mut crt);
+        cpu.breakpoints = [1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            cpu.run().unwrap_err(),
+            "Signal strength overflows during cycle 2, at address 1"
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Reads the letters drawn on the CRT.
+//!
+//! Letters sit at a fixed pitch: the usual font is 4 pixels wide and 6 tall
+//! with one blank column between letters, the large one 6 wide and 10 tall
+//! with two blank columns. Which font is used follows from the image height.
+
+use std::fmt::{self, Display, Formatter};
+
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    pub pitch: usize,
+    /// Each letter with its rows, `#` for a lit pixel.
+    pub glyphs: &'static [(char, &'static [&'static str])],
+}
+
+pub const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+pub const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+impl Font {
+    /// The font whose letters are `height` pixels tall.
+    pub fn for_height(height: usize) -> Option<&'static Font> {
+        [&SMALL, &LARGE].into_iter().find(|f| f.height == height)
+    }
+
+    fn glyph(&self, bitmap: &[Vec<bool>]) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|(_, rows)| {
+                rows.iter()
+                    .zip(bitmap)
+                    .all(|(row, pixels)| row.chars().map(|c| c == '#').eq(pixels.iter().copied()))
+            })
+            .map(|&(c, _)| c)
+    }
+}
+
+/// A letter cell that matches no glyph of the font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Position of the letter in the text, from 0.
+    pub index: usize,
+    /// First pixel column of the letter.
+    pub column: usize,
+    pub bitmap: Vec<Vec<bool>>,
+}
+
+impl Display for UnknownGlyph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown glyph at letter {} (column {}):",
+            self.index + 1,
+            self.column
+        )?;
+        for row in &self.bitmap {
+            let row: String = row.iter().map(|&b| if b { '#' } else { '.' }).collect();
+            write!(f, "\n{row}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// No font has letters of this height.
+    Height(usize),
+    /// Some letters weren't recognized; `text` has a `?` in their place.
+    Unknown {
+        text: String,
+        glyphs: Vec<UnknownGlyph>,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => write!(f, "No font has letters {height} pixels tall"),
+            OcrError::Unknown { text, glyphs } => {
+                write!(f, "Read {text:?} with {} unknown glyphs", glyphs.len())?;
+                for glyph in glyphs {
+                    write!(f, "\n{glyph}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reads the letters drawn on `rows`, ignoring empty rows. Rows shorter than
+/// the widest one are taken as dark on their end.
+pub fn read(rows: &[Vec<bool>]) -> Result<String, OcrError> {
+    let rows: Vec<&Vec<bool>> = rows.iter().filter(|r| !r.is_empty()).collect();
+    let font = Font::for_height(rows.len()).ok_or(OcrError::Height(rows.len()))?;
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let pixel = |y: usize, x: usize| rows[y].get(x).copied().unwrap_or(false);
+
+    let mut text = String::new();
+    let mut unknown = vec![];
+    for (index, column) in (0..width).step_by(font.pitch).enumerate() {
+        let bitmap: Vec<Vec<bool>> = (0..font.height)
+            .map(|y| (column..column + font.width).map(|x| pixel(y, x)).collect())
+            .collect();
+
+        match font.glyph(&bitmap) {
+            Some(c) => text.push(c),
+            None => {
+                text.push('?');
+                unknown.push(UnknownGlyph {
+                    index,
+                    column,
+                    bitmap,
+                });
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unknown {
+            text,
+            glyphs: unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(letters: &[&[&str]], pitch: usize) -> Vec<Vec<bool>> {
+        (0..letters[0].len())
+            .map(|y| {
+                letters
+                    .iter()
+                    .flat_map(|l| {
+                        let row = format!("{:.<pitch$}", l[y]);
+                        row.chars().map(|c| c == '#').collect::<Vec<_>>()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_both_fonts() {
+        let small = image(&[SMALL.glyphs[6].1, SMALL.glyphs[7].1], 5);
+        assert_eq!(read(&small), Ok("HI".into()));
+
+        let large = image(&[LARGE.glyphs[10].1, LARGE.glyphs[14].1], 8);
+        assert_eq!(read(&large), Ok("NZ".into()));
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let blob: &[&str] = &["####"; 6];
+        let rows = image(&[SMALL.glyphs[0].1, blob], 5);
+
+        match read(&rows) {
+            Err(OcrError::Unknown { text, glyphs }) => {
+                assert_eq!(text, "A?");
+                assert_eq!((glyphs[0].index, glyphs[0].column), (1, 5));
+                assert_eq!(glyphs[0].bitmap, vec![vec![true; 4]; 6]);
+            }
+            other => panic!("Expected an unknown glyph, got {other:?}"),
+        }
+    }
+}
@@ -1,220 +1,12 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{digit1, newline},
-    combinator::map,
-    multi::separated_list1,
-    sequence::{terminated, tuple},
-    IResult,
-};
-use std::io::{self, Read};
-
-#[derive(Debug, Clone)]
-enum Operation {
-    Add(i64),
-    Multiply(i64),
-    Square,
-}
-
-impl Operation {
-    fn perform(&self, i: i64) -> i64 {
-        match self {
-            Operation::Add(n) => i + n,
-            Operation::Multiply(n) => i * n,
-            Operation::Square => i * i,
-        }
-    }
-}
-
-#[derive(Debug, Clone)]
-struct Monkey {
-    items: Vec<i64>,
-    op: Operation,
-    divisible_by: i64,
-    forward_to: (usize, usize),
-    inspected: i64,
-}
-
-impl Monkey {
-    fn process(&self, very_worried: bool, worry_divisor: i64) -> Vec<(i64, usize)> {
-        self.items
-            .iter()
-            .map(|i| self.op.perform(*i))
-            .map(|i| {
-                if very_worried {
-                    i % worry_divisor
-                } else {
-                    i / worry_divisor
-                }
-            })
-            .map(|i| {
-                if i % self.divisible_by == 0 {
-                    (i, self.forward_to.0)
-                } else {
-                    (i, self.forward_to.1)
-                }
-            })
-            .collect()
-    }
-
-    fn clear(&mut self) {
-        self.items.clear();
-    }
-
-    fn append(&mut self, item: i64) {
-        self.items.push(item);
-    }
-
-    fn inspected(&mut self) {
-        self.inspected += 1;
-    }
-
-    fn total_inspections(&self) -> i64 {
-        self.inspected
-    }
-}
-
-fn parse_usize(s: &str) -> IResult<&str, usize> {
-    map(digit1, |i: &str| i.parse().expect("Invalid number"))(s)
-}
-
-fn parse_i64(s: &str) -> IResult<&str, i64> {
-    map(digit1, |i: &str| i.parse().expect("Invalid number"))(s)
-}
-
-fn monkey_header(s: &str) -> IResult<&str, ()> {
-    let (s, _) = terminated(tuple((tag("Monkey "), parse_usize, tag(":"))), newline)(s)?;
-    Ok((s, ()))
-}
-
-fn starting_items(s: &str) -> IResult<&str, Vec<i64>> {
-    let (s, (_, items)) = terminated(
-        tuple((
-            tag("  Starting items: "),
-            separated_list1(tag(", "), parse_i64),
-        )),
-        newline,
-    )(s)?;
-
-    Ok((s, items))
-}
-
-fn operation(s: &str) -> IResult<&str, Operation> {
-    let (s, (_, op)) = terminated(
-        tuple((
-            tag("  Operation: new = old "),
-            alt((
-                map(tag("* old"), |_| Operation::Square),
-                map(tuple((tag("* "), parse_i64)), |(_, i)| {
-                    Operation::Multiply(i)
-                }),
-                map(tuple((tag("+ "), parse_i64)), |(_, i)| Operation::Add(i)),
-            )),
-        )),
-        newline,
-    )(s)?;
-
-    Ok((s, op))
-}
-
-fn divisible_by(s: &str) -> IResult<&str, i64> {
-    let (s, (_, divisible_by)) =
-        terminated(tuple((tag("  Test: divisible by "), parse_i64)), newline)(s)?;
-
-    Ok((s, divisible_by))
-}
-
-fn forward_to(s: &str) -> IResult<&str, (usize, usize)> {
-    let (s, (_, true_monkey)) = terminated(
-        tuple((tag("    If true: throw to monkey "), parse_usize)),
-        newline,
-    )(s)?;
-    let (s, (_, false_monkey)) = terminated(
-        tuple((tag("    If false: throw to monkey "), parse_usize)),
-        newline,
-    )(s)?;
-
-    Ok((s, (true_monkey, false_monkey)))
-}
-
-fn parse_monkey(s: &str) -> IResult<&str, Monkey> {
-    let (s, _) = monkey_header(s)?;
-    let (s, items) = starting_items(s)?;
-    let (s, op) = operation(s)?;
-    let (s, divisible_by) = divisible_by(s)?;
-    let (s, forward_to) = forward_to(s)?;
-
-    Ok((
-        s,
-        Monkey {
-            items,
-            op,
-            divisible_by,
-            forward_to,
-            inspected: 0,
-        },
-    ))
-}
-
-fn monkeys(s: &str) -> IResult<&str, Vec<Monkey>> {
-    separated_list1(newline, parse_monkey)(s)
-}
-
-fn play_round(monkeys: &mut Vec<Monkey>, very_worried: bool, worry_divisor: i64) {
-    for i in 0..monkeys.len() {
-        let monkey = monkeys[i].clone();
-
-        monkey
-            .process(very_worried, worry_divisor)
-            .iter()
-            .for_each(|(item, to_monkey)| {
-                monkeys[*to_monkey].append(*item);
-                monkeys[i].inspected();
-            });
-
-        monkeys[i].clear();
-    }
-}
+use aoc::runner::Runner;
+use day11::Troop;
 
 fn main() {
-    let mut input = String::new();
-    io::stdin()
-        .lock()
-        .read_to_string(&mut input)
-        .expect("Couldn't read stdin");
-
-    {
-        let (_, mut monkeys) = monkeys(&input).expect("Failed to parse monkey business");
-
-        for _ in 0..20 {
-            play_round(&mut monkeys, false, 3);
-        }
-
-        let mut inspections: Vec<i64> = monkeys.iter().map(|m| m.total_inspections()).collect();
-        inspections.sort();
-        inspections.reverse();
-
-        println!(
-            "Step 1: Monkey business level: {}",
-            inspections[0] * inspections[1]
-        )
-    }
-
-    {
-        let (_, mut monkeys) = monkeys(&input).expect("Failed to parse monkey business");
-        let gcd: i64 = monkeys.iter().map(|m| m.divisible_by).product();
-
-        for _ in 0..10000 {
-            play_round(&mut monkeys, true, gcd);
-        }
-
-        let mut inspections: Vec<i64> = monkeys.iter().map(|m| m.total_inspections()).collect();
-        inspections.sort();
-        inspections.reverse();
+    let runner = Runner::new(2022, 11);
+    let troop: Troop = aoc::read_stdin()
+        .parse()
+        .expect("Failed to parse monkey business");
 
-        println!(
-            "Step 2: Monkey business level: {}",
-            inspections[0] * inspections[1]
-        )
-    }
+    runner.part(1, "Step 1: Monkey business level", || day11::part1(&troop));
+    runner.part(2, "Step 2: Monkey business level", || day11::part2(&troop));
 }
@@ -0,0 +1,182 @@
+//! Day 12: Hill Climbing Algorithm.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+fn shortest_path_length(target: usize, previous: &HashMap<usize, usize>) -> usize {
+    let mut u = target;
+    let mut path = vec![];
+
+    while previous.contains_key(&u) {
+        path.push(u);
+        u = previous[&u];
+    }
+
+    path.len()
+}
+
+fn find_item_with_smallest_distance(
+    queue: &VecDeque<usize>,
+    distances: &HashMap<usize, usize>,
+) -> usize {
+    queue
+        .iter()
+        .enumerate()
+        .min_by(
+            |(_, left), (_, right)| match (distances.get(left), distances.get(right)) {
+                (Some(left), Some(right)) => left.cmp(right),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                _ => Ordering::Equal,
+            },
+        )
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// The heightmap in row-major order, with `S` and `E` replaced by their heights.
+pub struct Map {
+    pub map: Vec<char>,
+    pub width: usize,
+    pub height: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Map {
+    fn neighbours_of(&self, pos: usize) -> Vec<usize> {
+        if pos >= self.map.len() {
+            return vec![];
+        }
+
+        let mut candidates = Vec::with_capacity(4);
+
+        if pos >= self.width {
+            candidates.push(pos - self.width);
+        }
+
+        if pos < self.width * (self.height - 1) {
+            candidates.push(pos + self.width);
+        }
+
+        if !pos.is_multiple_of(self.width) {
+            candidates.push(pos - 1);
+        }
+
+        if pos % self.width != self.width - 1 {
+            candidates.push(pos + 1);
+        }
+
+        candidates
+            .into_iter()
+            .filter(|p| {
+                let left = self.map[pos] as i32;
+                let right = self.map[*p] as i32;
+                left.abs_diff(right) <= 1 || left < right
+            })
+            .collect()
+    }
+
+    /// The heightmap from its rows, which must all be as wide and hold
+    /// exactly one `S` and one `E` between them.
+    pub fn new(map: Vec<Vec<char>>) -> Result<Self, String> {
+        let width = map.first().map_or(0, Vec::len);
+        let height = map.len();
+        if width == 0 {
+            return Err("The map is empty".into());
+        }
+
+        for (i, row) in map.iter().enumerate() {
+            if row.len() != width {
+                return Err(format!(
+                    "Line {}: expected {width} squares, got {}",
+                    i + 1,
+                    row.len()
+                ));
+            }
+            if let Some(c) = row
+                .iter()
+                .find(|&&c| !(c.is_ascii_lowercase() || c == 'S' || c == 'E'))
+            {
+                return Err(format!("Line {}: {c:?} is not an elevation", i + 1));
+            }
+        }
+
+        let mut map: Vec<char> = map.into_iter().flatten().collect();
+        let find = |square: char| match map.iter().filter(|&&c| c == square).count() {
+            1 => Ok(map.iter().position(|&c| c == square).unwrap()),
+            n => Err(format!("Expected one {square:?} square, found {n}")),
+        };
+        let start = find('S')?;
+        let end = find('E')?;
+
+        map[start] = 'a';
+        map[end] = 'z';
+
+        Ok(Self {
+            map,
+            width,
+            height,
+            start,
+            end,
+        })
+    }
+
+    /// For every reachable position, the next step on a shortest path to `E`.
+    pub fn solve(&self) -> HashMap<usize, usize> {
+        let mut queue = VecDeque::from_iter(self.map.iter().enumerate().map(|(i, _)| i));
+        let mut distances = HashMap::from([(self.end, 0)]);
+        let mut previous = HashMap::new();
+
+        while !queue.is_empty() {
+            let pos = find_item_with_smallest_distance(&queue, &distances);
+            let u = queue.remove(pos).expect("Couldn't unqueue item");
+
+            if distances.contains_key(&u) {
+                let distance = distances[&u] + 1;
+
+                self.neighbours_of(u)
+                    .iter()
+                    .filter(|v| queue.contains(v))
+                    .for_each(|v| {
+                        if distance < *distances.get(v).unwrap_or(&usize::MAX) {
+                            distances.insert(*v, distance);
+                            previous.insert(*v, u);
+                        }
+                    });
+            }
+        }
+
+        previous
+    }
+}
+
+impl FromStr for Map {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Map::new(s.lines().map(|l| l.chars().collect()).collect())
+    }
+}
+
+/// Fewest steps from `S` to `E`.
+pub fn part1(map: &Map) -> usize {
+    shortest_path_length(map.start, &map.solve())
+}
+
+/// Fewest steps from any lowest square to `E`.
+pub fn part2(map: &Map) -> usize {
+    let previous = map.solve();
+
+    map.map
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| **c == 'a')
+        .map(|(i, _)| shortest_path_length(i, &previous))
+        .filter(|n| *n != 0)
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,289 @@
+//! Day 14: Regolith Reservoir.
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, newline},
+    combinator::map,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum Item {
+    Sand,
+    Rock,
+    SandProducer,
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Item::Sand => 'o',
+            Item::Rock => '#',
+            Item::SandProducer => '+',
+        };
+
+        f.write_char(c)
+    }
+}
+
+/// The cave slice, keyed by column then row.
+#[derive(Debug)]
+pub struct Map {
+    columns: HashMap<i32, HashMap<i32, Item>>,
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Map {
+            columns: HashMap::new(),
+        }
+    }
+
+    fn get(&self, loc: Point) -> Option<&Item> {
+        self.columns.get(&loc.x).and_then(|c| c.get(&loc.y))
+    }
+
+    fn insert(&mut self, loc: Point, item: Item) {
+        self.columns.entry(loc.x).or_default().insert(loc.y, item);
+    }
+
+    pub fn insert_wall(&mut self, wall: Wall) {
+        match ((wall.from.x, wall.from.y), (wall.to.x, wall.to.y)) {
+            ((x1, y1), (x2, y2)) if x1 == x2 && y1 < y2 => {
+                for i in y1..=y2 {
+                    self.insert(Point { x: x1, y: i }, Item::Rock);
+                }
+            }
+            ((x1, y1), (x2, y2)) if x1 == x2 && y1 > y2 => {
+                for i in y2..=y1 {
+                    self.insert(Point { x: x1, y: i }, Item::Rock);
+                }
+            }
+            ((x1, y1), (x2, y2)) if y1 == y2 && x1 < x2 => {
+                for i in x1..=x2 {
+                    self.insert(Point { x: i, y: y1 }, Item::Rock);
+                }
+            }
+            ((x1, y1), (x2, y2)) if y1 == y2 && x1 > x2 => {
+                for i in x2..=x1 {
+                    self.insert(Point { x: i, y: y1 }, Item::Rock);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Drops one unit of sand, returning whether more can follow.
+    pub fn produce(&mut self) -> bool {
+        let mut location = Point { x: 500, y: 0 };
+
+        loop {
+            let cell = self.get(location);
+
+            if cell.is_none() || cell == Some(&Item::SandProducer) {
+                location.down();
+
+                if location.is_out_of_bounds() {
+                    break false;
+                }
+            } else {
+                location.left();
+
+                if self.get(location).is_some() {
+                    location.double_right();
+                    if self.get(location).is_some() {
+                        location.revert();
+
+                        if self.get(location) == Some(&Item::SandProducer) {
+                            self.insert(location, Item::Sand);
+                            break false;
+                        } else {
+                            self.insert(location, Item::Sand);
+                            break true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn count_sand(&self) -> usize {
+        self.columns
+            .values()
+            .flat_map(|c| c.values())
+            .filter(|v| **v == Item::Sand)
+            .count()
+    }
+
+    pub fn min_max_x(&self) -> (i32, i32) {
+        let keys = self.columns.keys();
+        let min = keys.clone().min().expect("Couldn't find minimum x value");
+        let max = keys.max().expect("Couldn't find maximum x value");
+
+        (*min, *max)
+    }
+
+    pub fn min_max_y(&self) -> (i32, i32) {
+        let keys = self.columns.values().flat_map(|c| c.keys());
+        let min = keys.clone().min().expect("Couldn't find minimum y value");
+        let max = keys.max().expect("Couldn't find maximum y value");
+
+        (*min, *max)
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min_x, max_x) = self.min_max_x();
+        let (min_x, max_x) = (min_x - 3, max_x + 3);
+        let (min_y, max_y) = self.min_max_y();
+        let (min_y, max_y) = (min_y - 3, max_y + 3);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let p = Point { x, y };
+                if let Some(item) = self.get(p) {
+                    f.write_fmt(format_args!("{}", item))?;
+                } else {
+                    f.write_char('.')?;
+                }
+            }
+
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    fn down(&mut self) {
+        self.y += 1;
+    }
+
+    fn left(&mut self) {
+        self.x -= 1;
+    }
+
+    fn double_right(&mut self) {
+        self.x += 2;
+    }
+
+    fn revert(&mut self) {
+        self.x -= 1;
+        self.y -= 1;
+    }
+
+    fn is_out_of_bounds(&self) -> bool {
+        self.y > 200
+    }
+}
+
+pub struct Wall {
+    pub from: Point,
+    pub to: Point,
+}
+
+fn parse_i32(s: &str) -> IResult<&str, i32> {
+    map(digit1, |i: &str| i.parse().expect("Invalid number"))(s)
+}
+
+fn parse_point(s: &str) -> IResult<&str, Point> {
+    let (s, (x, _, y)) = tuple((parse_i32, tag(","), parse_i32))(s)?;
+
+    Ok((s, Point { x, y }))
+}
+
+fn parse_line(s: &str) -> IResult<&str, Vec<Point>> {
+    separated_list1(tag(" -> "), parse_point)(s)
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Map {
+    /// Adds the infinite floor two rows below the lowest rock, as wide as the
+    /// pile of sand can ever get.
+    pub fn add_floor(&mut self) {
+        let (_, max_y) = self.min_max_y();
+        let floor = max_y + 2;
+
+        self.insert_wall(Wall {
+            from: Point {
+                x: 500 - floor - 1,
+                y: floor,
+            },
+            to: Point {
+                x: 500 + floor + 1,
+                y: floor,
+            },
+        });
+    }
+
+    fn fill(&mut self) -> usize {
+        while self.produce() {}
+        self.count_sand()
+    }
+}
+
+/// The rock paths from the scan, one per line.
+#[derive(Debug)]
+pub struct Scan {
+    pub paths: Vec<Vec<Point>>,
+}
+
+impl FromStr for Scan {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, paths) = separated_list1(newline, parse_line)(s).map_err(|e| e.to_string())?;
+        Ok(Self { paths })
+    }
+}
+
+impl From<&Scan> for Map {
+    fn from(scan: &Scan) -> Self {
+        let mut map = Map::new();
+
+        map.insert(Point { x: 500, y: 0 }, Item::SandProducer);
+
+        scan.paths.iter().for_each(|points| {
+            points.windows(2).for_each(|points| {
+                map.insert_wall(Wall {
+                    from: points[0],
+                    to: points[1],
+                })
+            });
+        });
+
+        map
+    }
+}
+
+/// Units of sand at rest before sand starts flowing into the abyss.
+pub fn part1(scan: &Scan) -> usize {
+    Map::from(scan).fill()
+}
+
+/// Units of sand at rest once the source is blocked, with a floor in place.
+pub fn part2(scan: &Scan) -> usize {
+    let mut map = Map::from(scan);
+    map.add_floor();
+    map.fill()
+}
@@ -0,0 +1,270 @@
+//! Day 15: Beacon Exclusion Zone.
+
+use aoc::profile::Profile;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug)]
+pub struct SensorReadout {
+    pub sensor: Position,
+    pub nearest_beacon: Position,
+}
+
+impl SensorReadout {
+    pub fn new(s: Position, b: Position) -> Self {
+        Self {
+            sensor: s,
+            nearest_beacon: b,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LocationContents {
+    Empty,
+    Sensor,
+    Beacon,
+}
+
+impl Display for LocationContents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::Empty => '#',
+            Self::Sensor => 'S',
+            Self::Beacon => 'B',
+        };
+
+        f.write_char(c)
+    }
+}
+
+/// What is known about each location, keyed by column then row.
+#[derive(Debug)]
+pub struct Map {
+    columns: HashMap<i32, HashMap<i32, LocationContents>>,
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Self {
+            columns: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, pos: &Position, contents: LocationContents) {
+        self.columns
+            .entry(pos.x)
+            .or_default()
+            .insert(pos.y, contents);
+    }
+
+    fn min_max_x(&self) -> (i32, i32) {
+        let keys = self.columns.keys();
+        let min = keys.clone().min().expect("Couldn't find minimum x value");
+        let max = keys.max().expect("Couldn't find maximum x value");
+
+        (*min, *max)
+    }
+
+    fn min_max_y(&self) -> (i32, i32) {
+        let keys = self.columns.values().flat_map(|c| c.keys());
+        let min = keys.clone().min().expect("Couldn't find minimum y value");
+        let max = keys.max().expect("Couldn't find maximum y value");
+
+        (*min, *max)
+    }
+
+    fn get(&self, pos: &Position) -> Option<&LocationContents> {
+        self.columns.get(&pos.x).and_then(|c| c.get(&pos.y))
+    }
+
+    /// Locations in `row` where the distress beacon cannot be.
+    pub fn unavailable_locations_in_row(&self, row: i32) -> usize {
+        let (min_x, max_x) = self.min_max_x();
+        let mut count = 0;
+
+        for x in min_x..=max_x {
+            let p = Position { x, y: row };
+            if Some(&LocationContents::Empty) == self.get(&p) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min_x, max_x) = self.min_max_x();
+        let (min_x, max_x) = (min_x - 3, max_x + 3);
+        let (min_y, max_y) = self.min_max_y();
+        let (min_y, max_y) = (min_y - 3, max_y + 3);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let p = Position { x, y };
+                if let Some(item) = self.get(&p) {
+                    f.write_fmt(format_args!("{}", item))?;
+                } else {
+                    f.write_char('.')?;
+                }
+            }
+
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+fn extract_data(s: &str) -> Option<SensorReadout> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^Sensor at x=(?P<sx>[^,]+), y=(?P<sy>[^:]+): closest beacon is at x=(?P<bx>[^,]+), y=(?P<by>.+)$"
+        )
+        .expect("Invalid regex");
+    }
+    let c = RE.captures(s)?;
+    let value = |name: &str| c.name(name).and_then(|m| m.as_str().parse().ok());
+
+    Some(SensorReadout::new(
+        Position::new(value("sx")?, value("sy")?),
+        Position::new(value("bx")?, value("by")?),
+    ))
+}
+
+fn distance(left: &Position, right: &Position) -> i32 {
+    (left.x.abs_diff(right.x) + left.y.abs_diff(right.y))
+        .try_into()
+        .unwrap()
+}
+
+fn calculate_range(sensor: &Position, beacon: &Position) -> Vec<Position> {
+    let delta_x = sensor.x.abs_diff(beacon.x) as i32 * 4;
+    let delta_y = sensor.y.abs_diff(beacon.y) as i32 * 4;
+
+    let min_x = sensor.x - delta_x;
+    let max_x = sensor.x + delta_x;
+    let min_y = sensor.y - delta_y;
+    let max_y = sensor.y + delta_y;
+
+    let max_distance = distance(sensor, beacon);
+
+    let mut positions = vec![];
+    for x in min_x..max_x {
+        for y in min_y..max_y {
+            let pos = Position::new(x, y);
+            let dist = distance(sensor, &pos);
+
+            if dist <= max_distance {
+                positions.push(pos);
+            }
+        }
+    }
+
+    positions
+}
+
+/// Puzzle parameters that differ between the example and the real input.
+pub struct Params {
+    pub row: i32,
+}
+
+impl From<Profile> for Params {
+    fn from(profile: Profile) -> Self {
+        match profile {
+            Profile::Example => Params { row: 10 },
+            Profile::Real => Params { row: 2_000_000 },
+        }
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "row = {}", self.row)
+    }
+}
+
+/// Tells the example apart from real inputs by the size of its coordinates.
+pub fn fingerprint(s: &str) -> Option<Profile> {
+    let largest = s
+        .lines()
+        .filter_map(extract_data)
+        .flat_map(|r| {
+            [
+                r.sensor.x,
+                r.sensor.y,
+                r.nearest_beacon.x,
+                r.nearest_beacon.y,
+            ]
+        })
+        .map(i32::abs)
+        .max()?;
+
+    if largest < 100 {
+        Some(Profile::Example)
+    } else {
+        Some(Profile::Real)
+    }
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Every sensor with its closest beacon.
+#[derive(Debug)]
+pub struct Report {
+    pub readouts: Vec<SensorReadout>,
+}
+
+impl FromStr for Report {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let readouts = s
+            .lines()
+            .map(|l| extract_data(l).ok_or(format!("Invalid sensor readout: {l:?}")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { readouts })
+    }
+}
+
+/// Locations in `row` that cannot contain a beacon.
+pub fn part1(report: &Report, row: i32) -> usize {
+    let mut map = Map::new();
+
+    report
+        .readouts
+        .iter()
+        .flat_map(|r| calculate_range(&r.sensor, &r.nearest_beacon))
+        .for_each(|pos| map.insert(&pos, LocationContents::Empty));
+
+    report.readouts.iter().for_each(|r| {
+        map.insert(&r.sensor, LocationContents::Sensor);
+        map.insert(&r.nearest_beacon, LocationContents::Beacon);
+    });
+
+    map.unavailable_locations_in_row(row)
+}
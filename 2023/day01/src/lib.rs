@@ -0,0 +1,62 @@
+//! Day 1: Trebuchet?!
+
+use std::str::FromStr;
+
+fn spell_out(input: &str) -> String {
+    input
+        .replace("zero", "z0o")
+        .replace("one", "o1e")
+        .replace("two", "t2o")
+        .replace("three", "t3e")
+        .replace("four", "f4r")
+        .replace("five", "f5e")
+        .replace("six", "s6x")
+        .replace("seven", "s7n")
+        .replace("eight", "e8t")
+        .replace("nine", "n9e")
+}
+
+/// The first and last digit of a line, read as a two-digit number.
+pub fn calibration_value(line: &str) -> Option<u32> {
+    let first = line.chars().find_map(|c| c.to_digit(10))?;
+    let last = line.chars().rev().find_map(|c| c.to_digit(10))?;
+
+    Some(first * 10 + last)
+}
+
+/// The amended calibration document, one line per value.
+#[derive(Debug)]
+pub struct Document {
+    pub text: String,
+}
+
+impl FromStr for Document {
+    type Err = String;
+
+    /// Lines are made of ASCII letters and digits only.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for (i, line) in s.lines().enumerate() {
+            if let Some(c) = line.chars().find(|c| !c.is_ascii_alphanumeric()) {
+                return Err(format!("Line {}: unexpected character {c:?}", i + 1));
+            }
+        }
+
+        Ok(Self {
+            text: s.to_string(),
+        })
+    }
+}
+
+/// Sum of calibration values using digits only. Lines without any digit are
+/// skipped.
+pub fn part1(document: &Document) -> u32 {
+    document.text.lines().filter_map(calibration_value).sum()
+}
+
+/// Sum of calibration values where spelled-out digits count too.
+pub fn part2(document: &Document) -> u32 {
+    spell_out(&document.text)
+        .lines()
+        .filter_map(calibration_value)
+        .sum()
+}
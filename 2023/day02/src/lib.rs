@@ -0,0 +1,143 @@
+//! Day 2: Cube Conundrum.
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, newline},
+    combinator::map,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+use std::{cmp::max, str::FromStr};
+
+enum Cubes {
+    Red(u8),
+    Green(u8),
+    Blue(u8),
+}
+
+/// Cubes revealed in one handful.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hand {
+    pub red: u8,
+    pub blue: u8,
+    pub green: u8,
+}
+
+#[derive(Clone, Debug)]
+pub struct Game {
+    pub id: u8,
+    pub hands: Vec<Hand>,
+}
+
+impl Game {
+    /// Whether the bag could hold only 12 red, 13 green and 14 blue cubes.
+    pub fn is_possible(&self) -> bool {
+        for hand in &self.hands {
+            if hand.red > 12 || hand.green > 13 || hand.blue > 14 {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    pub fn minimum_cubes(&self) -> (u8, u8, u8) {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for hand in &self.hands {
+            red = max(red, hand.red);
+            green = max(green, hand.green);
+            blue = max(blue, hand.blue);
+        }
+
+        (red, green, blue)
+    }
+
+    pub fn power(&self) -> u32 {
+        let mins = self.minimum_cubes();
+
+        mins.0 as u32 * mins.1 as u32 * mins.2 as u32
+    }
+}
+
+fn parse_u8(s: &str) -> IResult<&str, u8> {
+    map(digit1, |i: &str| i.parse().expect("Invalid number"))(s)
+}
+
+fn parse_red(s: &str) -> IResult<&str, Cubes> {
+    let (s, (n, _)) = tuple((parse_u8, tag(" red")))(s)?;
+
+    Ok((s, Cubes::Red(n)))
+}
+
+fn parse_green(s: &str) -> IResult<&str, Cubes> {
+    let (s, (n, _)) = tuple((parse_u8, tag(" green")))(s)?;
+
+    Ok((s, Cubes::Green(n)))
+}
+
+fn parse_blue(s: &str) -> IResult<&str, Cubes> {
+    let (s, (n, _)) = tuple((parse_u8, tag(" blue")))(s)?;
+
+    Ok((s, Cubes::Blue(n)))
+}
+
+fn parse_cubes(s: &str) -> IResult<&str, Cubes> {
+    alt((parse_red, parse_green, parse_blue))(s)
+}
+
+fn parse_hand(s: &str) -> IResult<&str, Hand> {
+    let (s, presented_cubes) = separated_list1(tag(", "), parse_cubes)(s)?;
+    let mut h = Hand::default();
+
+    for cubes in presented_cubes {
+        match cubes {
+            Cubes::Red(n) => h.red = n,
+            Cubes::Green(n) => h.green = n,
+            Cubes::Blue(n) => h.blue = n,
+        }
+    }
+
+    Ok((s, h))
+}
+
+fn parse_game(s: &str) -> IResult<&str, Game> {
+    let (s, (_, game_id, _)) = tuple((tag("Game "), parse_u8, tag(": ")))(s)?;
+    let (s, hands) = separated_list1(tag("; "), parse_hand)(s)?;
+
+    Ok((s, Game { id: game_id, hands }))
+}
+
+/// Every game played, one per line.
+#[derive(Debug)]
+pub struct Record {
+    pub games: Vec<Game>,
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, games) = separated_list1(newline, parse_game)(s).map_err(|e| e.to_string())?;
+        Ok(Self { games })
+    }
+}
+
+/// Sum of the IDs of the games that were possible.
+pub fn part1(record: &Record) -> u32 {
+    record
+        .games
+        .iter()
+        .filter_map(|g| if g.is_possible() { Some(g.id) } else { None })
+        .map(|id| id as u32)
+        .sum()
+}
+
+/// Sum of the powers of the smallest possible bag for each game.
+pub fn part2(record: &Record) -> u32 {
+    record.games.iter().map(|g| g.power()).sum()
+}
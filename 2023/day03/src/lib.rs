@@ -0,0 +1,208 @@
+//! Day 3: Gear Ratios.
+
+use itertools::Itertools;
+use std::{cmp::max, str::FromStr};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Coords {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coords {
+    pub fn adjacents(&self) -> Vec<Coords> {
+        let mut adjacents = vec![
+            Coords {
+                x: self.x,
+                y: self.y + 1,
+            },
+            Coords {
+                x: self.x + 1,
+                y: self.y + 1,
+            },
+            Coords {
+                x: self.x + 1,
+                y: self.y,
+            },
+        ];
+
+        if self.x > 0 {
+            adjacents.push(Coords {
+                x: self.x - 1,
+                y: self.y,
+            });
+
+            adjacents.push(Coords {
+                x: self.x - 1,
+                y: self.y + 1,
+            });
+        }
+
+        if self.y > 0 {
+            adjacents.push(Coords {
+                x: self.x,
+                y: self.y - 1,
+            });
+
+            adjacents.push(Coords {
+                x: self.x + 1,
+                y: self.y - 1,
+            });
+
+            if self.x > 0 {
+                adjacents.push(Coords {
+                    x: self.x - 1,
+                    y: self.y - 1,
+                });
+            }
+        }
+
+        adjacents
+    }
+}
+
+/// A number of the schematic, with the coordinates of its first digit.
+#[derive(Debug, Clone, Copy)]
+pub struct Number {
+    pub value: u32,
+    pub coords: Coords,
+    pub length: usize,
+}
+
+impl Number {
+    pub fn all_coords(&self) -> Vec<Coords> {
+        (self.coords.y..(self.coords.y + self.length))
+            .map(|y| Coords {
+                x: self.coords.x,
+                y,
+            })
+            .collect()
+    }
+
+    pub fn adjacents(&self) -> Vec<Coords> {
+        self.all_coords()
+            .iter()
+            .flat_map(|c| c.adjacents())
+            .unique()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Symbol {
+    pub coords: Coords,
+    pub is_gear: bool,
+}
+
+fn flush(numbers: &mut Vec<Number>, number: &mut String, coords: Coords) -> Result<(), String> {
+    if !number.is_empty() {
+        let n = number
+            .parse()
+            .map_err(|_| format!("Line {}: number {number} is too large", coords.x + 1))?;
+
+        numbers.push(Number {
+            value: n,
+            coords: Coords {
+                x: coords.x,
+                y: coords.y - number.len(),
+            },
+            length: number.len(),
+        });
+
+        number.clear();
+    }
+
+    Ok(())
+}
+
+/// Numbers and symbols of the engine schematic.
+#[derive(Debug)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl FromStr for Schematic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numbers, symbols) = parse(s)?;
+
+        Ok(Self { numbers, symbols })
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<Number>, Vec<Symbol>), String> {
+    let mut numbers = vec![];
+    let mut symbols = vec![];
+    let mut number = String::new();
+    let mut line_length = 0;
+
+    for (x, l) in input.lines().enumerate() {
+        line_length = max(line_length, l.len());
+
+        for (y, c) in l.chars().enumerate() {
+            let coords = Coords { x, y };
+            match c {
+                '.' => flush(&mut numbers, &mut number, coords)?,
+                '0'..='9' => number.push(c),
+                c => {
+                    flush(&mut numbers, &mut number, coords)?;
+                    symbols.push(Symbol {
+                        coords,
+                        is_gear: c == '*',
+                    });
+                }
+            }
+        }
+
+        flush(
+            &mut numbers,
+            &mut number,
+            Coords {
+                x,
+                y: line_length - 1,
+            },
+        )?;
+    }
+
+    Ok((numbers, symbols))
+}
+
+/// Sum of the numbers adjacent to a symbol.
+pub fn part1(schematic: &Schematic) -> u32 {
+    let Schematic { numbers, symbols } = schematic;
+
+    numbers
+        .iter()
+        .filter(|n| {
+            n.adjacents()
+                .iter()
+                .any(|coords| symbols.iter().any(|s| s.coords == *coords))
+        })
+        .map(|n| n.value)
+        .sum()
+}
+
+/// Sum of the ratios of every gear, a `*` next to exactly two numbers.
+pub fn part2(schematic: &Schematic) -> u32 {
+    let Schematic { numbers, symbols } = schematic;
+
+    symbols
+        .iter()
+        .filter(|s| s.is_gear)
+        .filter_map(|s| {
+            let v: Vec<_> = numbers
+                .iter()
+                .filter(|n| n.adjacents().iter().any(|c| c == &s.coords))
+                .map(|n| n.value)
+                .collect();
+
+            if v.len() == 2 {
+                Some(v.iter().product::<u32>())
+            } else {
+                None
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,90 @@
+//! Day 4: Scratchcards.
+
+use std::str::FromStr;
+
+/// A scratchcard along with how many copies of it we hold.
+#[derive(Debug, Clone)]
+pub struct Card {
+    pub copies: u32,
+    pub winning_numbers: Vec<u8>,
+    pub my_numbers: Vec<u8>,
+}
+
+impl Card {
+    pub fn points(&self) -> u16 {
+        match self.matches() {
+            0 => 0,
+            n => 2_u16.pow((n - 1) as u32),
+        }
+    }
+
+    pub fn matches(&self) -> u8 {
+        self.my_numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .count() as u8
+    }
+
+    fn increment(&mut self, copies: u32) {
+        self.copies += copies;
+    }
+}
+
+/// The pile of original scratchcards.
+#[derive(Debug, Clone)]
+pub struct Pile {
+    pub cards: Vec<Card>,
+}
+
+impl FromStr for Pile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .lines()
+            .map(|l| {
+                let (_, numbers) = l
+                    .split_once(": ")
+                    .ok_or_else(|| format!("Missing card header: {l}"))?;
+                let (winning, mine) = numbers
+                    .split_once(" | ")
+                    .ok_or_else(|| format!("Missing separator: {l}"))?;
+                let numbers = |s: &str| {
+                    s.split(' ')
+                        .filter_map(|d| d.parse::<u8>().ok())
+                        .collect::<Vec<_>>()
+                };
+
+                Ok(Card {
+                    copies: 1,
+                    winning_numbers: numbers(winning),
+                    my_numbers: numbers(mine),
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self { cards })
+    }
+}
+
+/// Total points of the pile.
+pub fn part1(pile: &Pile) -> u16 {
+    pile.cards.iter().map(|c| c.points()).sum()
+}
+
+/// Number of scratchcards we end up with once won copies are counted.
+pub fn part2(pile: &Pile) -> u32 {
+    let mut cards = pile.cards.clone();
+
+    for i in 0..cards.len() {
+        let matches = cards[i].matches();
+        let copies = cards[i].copies;
+
+        for j in (1..=matches).rev() {
+            let idx = i + j as usize;
+            cards[idx].increment(copies);
+        }
+    }
+
+    cards.iter().map(|c| c.copies).sum()
+}
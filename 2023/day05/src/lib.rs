@@ -0,0 +1,152 @@
+//! Day 5: If You Give A Seed A Fertilizer.
+
+use std::str::FromStr;
+
+/// One line of a map, sending a source span onto a destination span.
+#[derive(Debug)]
+pub struct Range {
+    pub source_start: u32,
+    pub destination_start: u32,
+    pub length: u32,
+}
+
+impl FromStr for Range {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let v: Vec<u32> = s
+            .split_whitespace()
+            .map(|n| n.parse().map_err(|_| format!("invalid number {n:?}")))
+            .collect::<Result<_, _>>()?;
+        let [destination_start, source_start, length] = v[..] else {
+            return Err(format!("expected three numbers, got {s:?}"));
+        };
+
+        Ok(Range {
+            destination_start,
+            source_start,
+            length,
+        })
+    }
+}
+
+impl Range {
+    pub fn transpose(&self, value: u32) -> Option<u32> {
+        if value >= self.source_start && value < (self.source_start + self.length) {
+            if self.source_start > self.destination_start {
+                Some(value - (self.source_start - self.destination_start))
+            } else {
+                Some(value + (self.destination_start - self.source_start))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// One category-to-category map of the almanac.
+#[derive(Debug)]
+pub struct Map {
+    pub ranges: Vec<Range>,
+}
+
+impl FromStr for Map {
+    type Err = String;
+
+    /// One range per line, skipping the `x-to-y map:` header.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ranges = s
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.contains(':'))
+            .map(|(i, l)| l.parse().map_err(|e| format!("Line {}: {e}", i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map { ranges })
+    }
+}
+
+impl Map {
+    pub fn transpose(&self, value: u32) -> Option<u32> {
+        let v = self
+            .ranges
+            .iter()
+            .map(|r| r.transpose(value))
+            .find(|v| v.is_some())
+            .unwrap_or(None);
+
+        v
+    }
+}
+
+/// Seeds to plant along with every map from seed to location.
+#[derive(Debug, Default)]
+pub struct Almanac {
+    pub seeds: Vec<u32>,
+    pub maps: Vec<Map>,
+}
+
+impl FromStr for Almanac {
+    type Err = String;
+
+    /// The `seeds:` line, then each map introduced by its header line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+
+        let seeds = match lines.next().map(|(_, l)| (l, l.strip_prefix("seeds:"))) {
+            Some((_, Some(seeds))) => seeds
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| format!("Line 1: invalid seed {n:?}")))
+                .collect::<Result<_, _>>()?,
+            Some((l, None)) => return Err(format!("Line 1: expected seeds, got {l:?}")),
+            None => return Err("Expected seeds".into()),
+        };
+
+        let mut maps: Vec<Map> = vec![];
+        for (i, l) in lines {
+            if l.is_empty() {
+                continue;
+            } else if l.contains(':') {
+                maps.push(Map { ranges: vec![] });
+            } else {
+                let range = l.parse().map_err(|e| format!("Line {}: {e}", i + 1))?;
+                maps.last_mut()
+                    .ok_or_else(|| format!("Line {}: range before any map header", i + 1))?
+                    .ranges
+                    .push(range);
+            }
+        }
+
+        Ok(Almanac { seeds, maps })
+    }
+}
+
+impl Almanac {
+    pub fn find_locations(&self) -> Vec<u32> {
+        self.seeds.iter().map(|s| self.transpose(*s)).collect()
+    }
+
+    pub fn find_all_locations(&self) -> Vec<u32> {
+        self.seeds
+            .chunks(2)
+            .flat_map(|c| (c[0]..(c[0] + c[1])).collect::<Vec<_>>())
+            .map(|s| self.transpose(s))
+            .collect()
+    }
+
+    pub fn transpose(&self, seed: u32) -> u32 {
+        self.maps
+            .iter()
+            .fold(seed, |v, m| m.transpose(v).unwrap_or(v))
+    }
+}
+
+/// Lowest location of any of the listed seeds.
+pub fn part1(almanac: &Almanac) -> u32 {
+    *almanac.find_locations().iter().min().unwrap()
+}
+
+/// Lowest location once the seeds are read as ranges.
+pub fn part2(almanac: &Almanac) -> u32 {
+    *almanac.find_all_locations().iter().min().unwrap()
+}
@@ -1,145 +1,10 @@
-use std::{
-    io::{self, Read},
-    str::FromStr,
-};
-
-#[derive(Debug)]
-struct Range {
-    source_start: u32,
-    destination_start: u32,
-    length: u32,
-}
-
-impl FromStr for Range {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let v: Vec<_> = s.split(' ').map(str::parse).map(|f| f.unwrap()).collect();
-
-        Ok(Range {
-            destination_start: v[0],
-            source_start: v[1],
-            length: v[2],
-        })
-    }
-}
-
-impl Range {
-    fn transpose(&self, value: u32) -> Option<u32> {
-        if value >= self.source_start && value < (self.source_start + self.length) {
-            if self.source_start > self.destination_start {
-                Some(value - (self.source_start - self.destination_start))
-            } else {
-                Some(value + (self.destination_start - self.source_start))
-            }
-        } else {
-            None
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Map {
-    ranges: Vec<Range>,
-}
-
-impl FromStr for Map {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let ranges = s
-            .lines()
-            .filter_map(|l| {
-                if l.contains(':') {
-                    None
-                } else {
-                    Some(Range::from_str(l).unwrap())
-                }
-            })
-            .collect();
-
-        Ok(Map { ranges })
-    }
-}
-
-impl Map {
-    fn transpose(&self, value: u32) -> Option<u32> {
-        let v = self
-            .ranges
-            .iter()
-            .map(|r| r.transpose(value))
-            .find(|v| v.is_some())
-            .unwrap_or(None);
-
-        v
-    }
-}
-
-#[derive(Debug, Default)]
-struct Almanac {
-    seeds: Vec<u32>,
-    maps: Vec<Map>,
-}
-
-impl FromStr for Almanac {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut seeds = vec![];
-
-        let maps = s
-            .split("\n\n")
-            .filter_map(|part| {
-                if part.contains('\n') {
-                    Some(Map::from_str(part).unwrap())
-                } else {
-                    seeds = part[7..].split(' ').map(|s| s.parse().unwrap()).collect();
-                    None
-                }
-            })
-            .collect();
-
-        Ok(Almanac { seeds, maps })
-    }
-}
-
-impl Almanac {
-    fn find_locations(&self) -> Vec<u32> {
-        self.seeds.iter().map(|s| self.transpose(*s)).collect()
-    }
-
-    fn find_all_locations(&self) -> Vec<u32> {
-        self.seeds
-            .chunks(2)
-            .flat_map(|c| (c[0]..(c[0] + c[1])).collect::<Vec<_>>())
-            .map(|s| self.transpose(s))
-            .collect()
-    }
-
-    fn transpose(&self, seed: u32) -> u32 {
-        self.maps
-            .iter()
-            .fold(seed, |v, m| m.transpose(v).unwrap_or(v))
-    }
-}
-
-fn read_input() -> String {
-    let mut input = String::new();
-
-    io::stdin()
-        .lock()
-        .read_to_string(&mut input)
-        .expect("Couldn't read stdin");
-
-    input
-}
+use aoc::runner::Runner;
+use day05::Almanac;
 
 fn main() {
-    let input = read_input();
-    let almanac = Almanac::from_str(&input).unwrap();
-    let lowest_location = *almanac.find_locations().iter().min().unwrap();
-    let lowest_all_locations = *almanac.find_all_locations().iter().min().unwrap();
+    let runner = Runner::new(2023, 5);
+    let almanac: Almanac = aoc::read_stdin().parse().unwrap();
 
-    println!("Lowest location: {}", lowest_location);
-    println!("Lowest of all location: {}", lowest_all_locations);
+    runner.part(1, "Lowest location", || day05::part1(&almanac));
+    runner.part(2, "Lowest of all location", || day05::part2(&almanac));
 }
@@ -0,0 +1,152 @@
+//! Day 6: Wait For It.
+
+use aoc::profile::Profile;
+use std::str::FromStr;
+
+/// A race: how long it lasts and the record distance to beat.
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    pub fn outcomes(&self) -> Vec<u64> {
+        (0..=self.time)
+            .map(|speed| (self.time - speed) * speed)
+            .collect()
+    }
+}
+
+fn sample_races() -> Vec<Race> {
+    vec![
+        Race {
+            time: 7,
+            distance: 9,
+        },
+        Race {
+            time: 15,
+            distance: 40,
+        },
+        Race {
+            time: 30,
+            distance: 200,
+        },
+    ]
+}
+
+fn real_races() -> Vec<Race> {
+    vec![
+        Race {
+            time: 44,
+            distance: 277,
+        },
+        Race {
+            time: 89,
+            distance: 1136,
+        },
+        Race {
+            time: 96,
+            distance: 1890,
+        },
+        Race {
+            time: 91,
+            distance: 1768,
+        },
+    ]
+}
+
+/// The single race hidden by bad kerning of the sheet.
+pub fn kerned(races: &[Race]) -> Race {
+    let join = |values: Vec<u64>| -> u64 {
+        values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<String>()
+            .parse()
+            .expect("Kerned number is too large")
+    };
+
+    Race {
+        time: join(races.iter().map(|r| r.time).collect()),
+        distance: join(races.iter().map(|r| r.distance).collect()),
+    }
+}
+
+/// The races of the sheet; empty when the input does not list them.
+pub struct Races {
+    pub races: Vec<Race>,
+}
+
+impl Races {
+    /// Races typed in from the puzzle, for when there is no input file.
+    pub fn builtin(profile: Profile) -> Self {
+        let races = match profile {
+            Profile::Example => sample_races(),
+            Profile::Real => real_races(),
+        };
+
+        Self { races }
+    }
+}
+
+impl FromStr for Races {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { races: parse(s)? })
+    }
+}
+
+fn parse(s: &str) -> Result<Vec<Race>, String> {
+    let values = |prefix: &str| -> Result<Vec<u64>, String> {
+        let Some((i, l)) = s
+            .lines()
+            .enumerate()
+            .find_map(|(i, l)| Some((i, l.strip_prefix(prefix)?)))
+        else {
+            return Ok(vec![]);
+        };
+
+        l.split_whitespace()
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| format!("Line {}: invalid number {v:?}", i + 1))
+            })
+            .collect()
+    };
+
+    Ok(values("Time:")?
+        .into_iter()
+        .zip(values("Distance:")?)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+pub fn fingerprint(s: &str) -> Option<Profile> {
+    let races = parse(s).ok()?;
+
+    if races.is_empty() {
+        None
+    } else if races.iter().all(|r| r.time <= 30) {
+        Some(Profile::Example)
+    } else {
+        Some(Profile::Real)
+    }
+}
+
+pub fn ways_to_win(race: &Race) -> usize {
+    race.outcomes()
+        .iter()
+        .filter(|o| **o > race.distance)
+        .count()
+}
+
+/// Product of the number of ways to beat each race.
+pub fn part1(races: &Races) -> usize {
+    races.races.iter().map(ways_to_win).product()
+}
+
+/// Ways to beat the single race once the spaces are ignored.
+pub fn part2(races: &Races) -> usize {
+    ways_to_win(&kerned(&races.races))
+}
@@ -0,0 +1,177 @@
+//! Day 7: Camel Cards.
+
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+/// A card, ordered by strength. `J` reads as a `Jack` in part 1 and as the
+/// weakest `Joker` in part 2.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    pub fn parse(value: char, jokers: bool) -> Option<Self> {
+        let card = match value {
+            'J' if jokers => Card::Joker,
+            '2' => Card::Two,
+            '3' => Card::Three,
+            '4' => Card::Four,
+            '5' => Card::Five,
+            '6' => Card::Six,
+            '7' => Card::Seven,
+            '8' => Card::Eight,
+            '9' => Card::Nine,
+            'T' => Card::Ten,
+            'J' => Card::Jack,
+            'Q' => Card::Queen,
+            'K' => Card::King,
+            'A' => Card::Ace,
+            _ => return None,
+        };
+
+        Some(card)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Hand {
+    HighCard,
+    Pair,
+    TwoPairs,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl From<&[Card]> for Hand {
+    fn from(cards: &[Card]) -> Self {
+        let mut freq: Vec<_> = cards
+            .iter()
+            .fold(HashMap::<Card, usize>::new(), |mut map, card| {
+                *map.entry(*card).or_default() += 1;
+                map
+            })
+            .into_iter()
+            .collect();
+
+        freq.sort_by(|a, b| match b.1.cmp(&a.1) {
+            Ordering::Equal => b.0.cmp(&a.0),
+            ord => ord,
+        });
+
+        if let Some(i) = freq.iter().position(|(c, _)| *c == Card::Joker) {
+            if freq.len() > 1 {
+                let dst = if i == 0 { 1 } else { 0 };
+
+                freq[dst].1 += freq[i].1;
+                freq.remove(i);
+            }
+        }
+
+        match freq.len() {
+            5 => Hand::HighCard,
+            4 => Hand::Pair,
+            3 if freq[0].1 == 2 => Hand::TwoPairs,
+            3 if freq[0].1 == 3 => Hand::ThreeOfAKind,
+            2 if freq[0].1 == 3 => Hand::FullHouse,
+            2 => Hand::FourOfAKind,
+            1 => Hand::FiveOfAKind,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Round {
+    pub cards: Vec<Card>,
+    pub hand: Hand,
+    pub bid: u16,
+}
+
+/// Hands and bids as listed, kept as text so `J` can be read either way.
+#[derive(Debug)]
+pub struct Game {
+    pub lines: Vec<(String, u16)>,
+}
+
+impl FromStr for Game {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s
+            .lines()
+            .map(|l| {
+                let (cards, bid) = l
+                    .split_once(' ')
+                    .ok_or_else(|| format!("Missing bid: {l}"))?;
+                if cards.chars().count() != 5
+                    || cards.chars().any(|c| Card::parse(c, false).is_none())
+                {
+                    return Err(format!("Invalid hand: {cards}"));
+                }
+                let bid = bid.parse().map_err(|_| format!("Invalid bid: {bid}"))?;
+
+                Ok((cards.to_string(), bid))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { lines })
+    }
+}
+
+impl Game {
+    pub fn rounds(&self, jokers: bool) -> Vec<Round> {
+        self.lines
+            .iter()
+            .map(|(cards, bid)| {
+                let cards: Vec<_> = cards
+                    .chars()
+                    .filter_map(|c| Card::parse(c, jokers))
+                    .collect();
+
+                Round {
+                    hand: Hand::from(cards.as_slice()),
+                    cards,
+                    bid: *bid,
+                }
+            })
+            .collect()
+    }
+}
+
+fn winnings(mut rounds: Vec<Round>) -> usize {
+    rounds.sort_by(|a, b| match a.hand.cmp(&b.hand) {
+        Ordering::Equal => a.cards.cmp(&b.cards),
+        ord => ord,
+    });
+
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (i + 1) * r.bid as usize)
+        .sum()
+}
+
+/// Total winnings with `J` as jacks.
+pub fn part1(game: &Game) -> usize {
+    winnings(game.rounds(false))
+}
+
+/// Total winnings with `J` as jokers.
+pub fn part2(game: &Game) -> usize {
+    winnings(game.rounds(true))
+}
@@ -0,0 +1,135 @@
+//! Day 8: Haunted Wasteland.
+
+use std::{collections::HashMap, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::alphanumeric1,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+use num::integer::lcm;
+
+#[derive(Debug)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Side {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Side::Left),
+            'R' => Ok(Side::Right),
+            _ => Err(format!("unexpected instruction {value:?}")),
+        }
+    }
+}
+
+/// The network of nodes along with the left/right instructions.
+pub struct Map {
+    pub map: HashMap<String, (String, String)>,
+    pub instructions: Vec<Side>,
+}
+
+impl Map {
+    /// Number of steps from `start` until a node satisfying `is_end`.
+    pub fn traverse(&self, start: &str, is_end: fn(&str) -> bool) -> usize {
+        let mut pos = start;
+        let mut i = 0;
+
+        loop {
+            for inst in &self.instructions {
+                pos = match inst {
+                    Side::Left => &self.map[pos].0,
+                    Side::Right => &self.map[pos].1,
+                };
+
+                i += 1;
+
+                if is_end(pos) {
+                    return i;
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Map {
+    type Err = String;
+
+    /// The instructions on the first line, then a blank line and one node
+    /// per line. Every node a node leads to must be listed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+
+        let instructions: Vec<Side> = lines
+            .next()
+            .map_or(Ok(vec![]), |(_, l)| l.chars().map(Side::try_from).collect())
+            .map_err(|e| format!("Line 1: {e}"))?;
+        if instructions.is_empty() {
+            return Err("Line 1: expected left/right instructions".into());
+        }
+        if let Some((_, l)) = lines.next().filter(|(_, l)| !l.is_empty()) {
+            return Err(format!("Line 2: expected a blank line, got {l:?}"));
+        }
+
+        let mut map = HashMap::new();
+        let mut destinations = vec![];
+        for (i, l) in lines {
+            let (node, (left, right)) = match line(l) {
+                Ok(("", node)) => node,
+                _ => {
+                    return Err(format!(
+                        "Line {}: expected AAA = (BBB, CCC), got {l:?}",
+                        i + 1
+                    ))
+                }
+            };
+
+            destinations.push((i, left, right));
+            map.insert(node.to_string(), (left.to_string(), right.to_string()));
+        }
+
+        for (i, left, right) in destinations {
+            if let Some(node) = [left, right].into_iter().find(|n| !map.contains_key(*n)) {
+                return Err(format!("Line {}: node {node} is never defined", i + 1));
+            }
+        }
+
+        Ok(Map { instructions, map })
+    }
+}
+
+fn coords(s: &str) -> IResult<&str, &str> {
+    alphanumeric1(s)
+}
+
+fn dst(s: &str) -> IResult<&str, (&str, &str)> {
+    delimited(
+        tag("("),
+        separated_pair(coords, tag(", "), coords),
+        tag(")"),
+    )(s)
+}
+
+fn line(s: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(coords, tag(" = "), dst)(s)
+}
+
+/// Steps from `AAA` to `ZZZ`.
+pub fn part1(map: &Map) -> usize {
+    map.traverse("AAA", |s| s == "ZZZ")
+}
+
+/// Steps until every ghost starting on a `..A` node stands on a `..Z` node.
+pub fn part2(map: &Map) -> usize {
+    map.map
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|k| map.traverse(k, |s| s.ends_with('Z')))
+        .fold(1, lcm)
+}
@@ -0,0 +1,73 @@
+//! Day 9: Mirage Maintenance.
+
+use std::{num::ParseIntError, str::FromStr};
+
+/// The history of every value of the report.
+#[derive(Debug)]
+pub struct Report {
+    pub histories: Vec<Vec<i32>>,
+}
+
+impl FromStr for Report {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let histories = s
+            .lines()
+            .map(|l| l.split(' ').map(str::parse).collect())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { histories })
+    }
+}
+
+/// Successive differences of `history`, down to the all-zero sequence.
+pub fn differences(history: &[i32]) -> Vec<Vec<i32>> {
+    let mut diffs = vec![history.to_vec()];
+
+    loop {
+        diffs.push(
+            diffs
+                .last()
+                .unwrap()
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .collect(),
+        );
+
+        if diffs.last().unwrap().iter().all(|x| *x == 0) {
+            break;
+        }
+    }
+
+    diffs
+}
+
+/// Sum of the next value of each history.
+pub fn part1(report: &Report) -> i32 {
+    report
+        .histories
+        .iter()
+        .map(|h| {
+            differences(h)
+                .iter()
+                .map(|d| *d.last().unwrap())
+                .sum::<i32>()
+        })
+        .sum()
+}
+
+/// Sum of the value before the first of each history.
+pub fn part2(report: &Report) -> i32 {
+    report
+        .histories
+        .iter()
+        .map(|h| {
+            differences(h)
+                .iter()
+                .map(|d| *d.first().unwrap())
+                .rev()
+                .fold(0, |acc, x| x - acc)
+        })
+        .sum()
+}
@@ -1,44 +1,10 @@
-use std::io::{self, BufRead};
+use aoc::runner::Runner;
+use day09::Report;
 
 fn main() {
-    let (first, last) = io::stdin()
-        .lock()
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .split(' ')
-                .map(|s| s.parse::<i32>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .map(|hist| {
-            let mut diffs = vec![hist];
+    let runner = Runner::new(2023, 9);
+    let report: Report = aoc::read_stdin().parse().unwrap();
 
-            loop {
-                diffs.push(
-                    diffs
-                        .last()
-                        .unwrap()
-                        .windows(2)
-                        .map(|w| w[1] - w[0])
-                        .collect(),
-                );
-
-                if diffs.last().unwrap().iter().all(|x| *x == 0) {
-                    break;
-                }
-            }
-
-            (
-                diffs
-                    .iter()
-                    .map(|d| *d.first().unwrap())
-                    .rev()
-                    .fold(0, |acc, x| x - acc),
-                diffs.iter().map(|d| *d.last().unwrap()).sum::<i32>(),
-            )
-        })
-        .fold((0, 0), |(accf, accl), (f, l)| (accf + f, accl + l));
-
-    println!("Sum of all predictions: {}", last);
-    println!("Sum of all extrapolations: {}", first);
+    runner.part(1, "Sum of all predictions", || day09::part1(&report));
+    runner.part(2, "Sum of all extrapolations", || day09::part2(&report));
 }
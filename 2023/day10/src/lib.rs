@@ -0,0 +1,248 @@
+//! Day 10: Pipe Maze.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A tile of the field, drawn with box-drawing characters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Entry {
+    Start,
+    Empty,
+    Horizontal,
+    Vertical,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Entry {
+    pub fn connects_bottom(&self, other: Self) -> bool {
+        match self {
+            Self::Start | Self::Vertical | Self::SouthEast | Self::SouthWest => matches!(
+                other,
+                Self::Start | Self::Vertical | Self::NorthEast | Self::NorthWest
+            ),
+            _ => false,
+        }
+    }
+
+    pub fn connects_top(&self, other: Self) -> bool {
+        match self {
+            Self::Start | Self::Vertical | Self::NorthEast | Self::NorthWest => matches!(
+                other,
+                Self::Start | Self::Vertical | Self::SouthEast | Self::SouthWest
+            ),
+            _ => false,
+        }
+    }
+
+    pub fn connects_left(&self, other: Self) -> bool {
+        match self {
+            Self::Start | Self::Horizontal | Self::NorthWest | Self::SouthWest => matches!(
+                other,
+                Self::Start | Self::Horizontal | Self::NorthEast | Self::SouthEast
+            ),
+            _ => false,
+        }
+    }
+
+    pub fn connects_right(&self, other: Self) -> bool {
+        match self {
+            Self::Start | Self::Horizontal | Self::NorthEast | Self::SouthEast => matches!(
+                other,
+                Self::Start | Self::Horizontal | Self::NorthWest | Self::SouthWest
+            ),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Entry::Start => '▣',
+                Entry::Empty => ' ',
+                Entry::Horizontal => '─',
+                Entry::Vertical => '│',
+                Entry::NorthEast => '└',
+                Entry::NorthWest => '┘',
+                Entry::SouthEast => '┌',
+                Entry::SouthWest => '┐',
+            }
+        )
+    }
+}
+
+impl TryFrom<char> for Entry {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(Entry::Start),
+            '.' => Ok(Entry::Empty),
+            '-' => Ok(Entry::Horizontal),
+            '|' => Ok(Entry::Vertical),
+            'L' => Ok(Entry::NorthEast),
+            'J' => Ok(Entry::NorthWest),
+            'F' => Ok(Entry::SouthEast),
+            '7' => Ok(Entry::SouthWest),
+            _ => Err(format!("unexpected tile {c:?}")),
+        }
+    }
+}
+
+/// The field of pipes, indexed by row then column.
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub map: Vec<Vec<Entry>>,
+}
+
+impl Map {
+    pub fn connections(&self, coords: (usize, usize)) -> Vec<(usize, usize)> {
+        let (x, y) = coords;
+        let rows = self.map.len();
+        let columns = self.map[0].len();
+        let entry = self.map[x][y];
+
+        let mut coords = vec![];
+
+        if x > 0 && entry.connects_top(self.map[x - 1][y]) {
+            coords.push((x - 1, y));
+        }
+
+        if x < (rows - 1) && entry.connects_bottom(self.map[x + 1][y]) {
+            coords.push((x + 1, y));
+        }
+
+        if y > 0 && entry.connects_left(self.map[x][y - 1]) {
+            coords.push((x, y - 1));
+        }
+
+        if y < (columns - 1) && entry.connects_right(self.map[x][y + 1]) {
+            coords.push((x, y + 1));
+        }
+
+        coords
+    }
+
+    /// Tiles of the loop going through the start, ending back on it.
+    pub fn main_loop(&self) -> Vec<(usize, usize)> {
+        let start = self.start_node();
+        let mut path = vec![start];
+
+        loop {
+            let conns: Vec<_> = self
+                .connections(*path.last().unwrap())
+                .into_iter()
+                .filter(|coords| !path.contains(coords))
+                .collect();
+
+            if conns.is_empty() {
+                path.push(start);
+                break;
+            }
+
+            path.push(conns[0]);
+        }
+
+        path
+    }
+
+    pub fn start_node(&self) -> (usize, usize) {
+        *self
+            .map
+            .iter()
+            .enumerate()
+            .filter_map(|(x, line)| {
+                let line: Vec<_> = line
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(y, e)| if *e == Entry::Start { Some(y) } else { None })
+                    .collect();
+
+                if line.len() == 1 {
+                    Some((x, *line.first().unwrap()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+            .first()
+            .unwrap()
+    }
+}
+
+impl FromStr for Map {
+    type Err = String;
+
+    /// One row per line, all of them as wide, with a single start tile.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Vec<Vec<Entry>> = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l.chars()
+                    .map(Entry::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| format!("Line {}: {e}", i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let width = map.first().map_or(0, Vec::len);
+        if let Some(i) = map.iter().position(|r| r.len() != width) {
+            return Err(format!(
+                "Line {}: expected {width} tiles, got {}",
+                i + 1,
+                map[i].len()
+            ));
+        }
+
+        match map.iter().flatten().filter(|&&e| e == Entry::Start).count() {
+            1 => Ok(Map { map }),
+            n => Err(format!("Expected one start tile, found {n}")),
+        }
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for line in &self.map {
+            for entry in line {
+                write!(f, "{}", entry)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Steps along the loop to the point farthest from the start.
+pub fn part1(map: &Map) -> usize {
+    map.main_loop().len() / 2
+}
+
+/// Tiles enclosed by the loop, from the shoelace formula and Pick's theorem.
+pub fn part2(map: &Map) -> i32 {
+    let path = map.main_loop();
+
+    let area = path
+        .windows(2)
+        .map(|w| {
+            let (x1, y1) = w[0];
+            let (x2, y2) = w[1];
+
+            (x1 * y2) as i32 - (x2 * y1) as i32
+        })
+        .sum::<i32>()
+        .abs()
+        / 2;
+
+    area + 1 - (path.len() / 2) as i32
+}
@@ -0,0 +1,186 @@
+//! Day 11: Cosmic Expansion.
+
+use aoc::profile::Profile;
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+/// How many extra rows or columns each empty one stands for, per part.
+pub struct Params {
+    pub expansion_factor: usize,
+    pub full_expansion_factor: usize,
+}
+
+impl From<Profile> for Params {
+    fn from(profile: Profile) -> Self {
+        match profile {
+            Profile::Example => Params {
+                expansion_factor: 1,
+                full_expansion_factor: 99,
+            },
+            Profile::Real => Params {
+                expansion_factor: 1,
+                full_expansion_factor: 999_999,
+            },
+        }
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expansion factors = {} and {}",
+            self.expansion_factor, self.full_expansion_factor
+        )
+    }
+}
+
+pub fn fingerprint(s: &str) -> Option<Profile> {
+    if s.lines().count() <= 10 {
+        Some(Profile::Example)
+    } else {
+        None
+    }
+}
+
+/// Galaxies of the image, plus the empty rows and columns between them.
+#[derive(Debug)]
+pub struct Image {
+    pub galaxies: Vec<(usize, usize)>,
+    pub vertical_expansions: Vec<usize>,
+    pub horizontal_expansions: Vec<usize>,
+}
+
+impl FromStr for Image {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scan = parse(s)?;
+
+        let vertical_expansions: Vec<_> = scan
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| {
+                if r.iter().all(|x| x.is_none()) {
+                    Some(i)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let horizontal_expansions: Vec<_> = scan.iter().fold(
+            empty_cols(scan.first().map_or(&[], Vec::as_slice)),
+            |acc, r| {
+                r.iter()
+                    .enumerate()
+                    .filter_map(|(i, x)| if x.is_none() { Some(i) } else { None })
+                    .filter(|x| acc.contains(x))
+                    .collect()
+            },
+        );
+
+        let galaxies: Vec<_> = scan
+            .iter()
+            .enumerate()
+            .flat_map(|(x, r)| {
+                r.iter()
+                    .enumerate()
+                    .filter_map(|(y, c)| if c.is_some() { Some((x, y)) } else { None })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Ok(Self {
+            galaxies,
+            vertical_expansions,
+            horizontal_expansions,
+        })
+    }
+}
+
+fn parse(s: &str) -> Result<Vec<Vec<Option<()>>>, String> {
+    s.lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l.chars()
+                .map(|c| match c {
+                    '.' => Ok(None),
+                    '#' => Ok(Some(())),
+                    _ => Err(format!("Line {}: unexpected character {c:?}", i + 1)),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn empty_cols(row: &[Option<()>]) -> Vec<usize> {
+    row.iter()
+        .enumerate()
+        .filter_map(|(i, x)| if x.is_none() { Some(i) } else { None })
+        .collect()
+}
+
+/// Sum of the distances between every pair of galaxies.
+pub fn calculate_sum(
+    galaxies: &[(usize, usize)],
+    vertical_expansions: &[usize],
+    horizontal_expansions: &[usize],
+    expansion_factor: usize,
+) -> usize {
+    galaxies
+        .iter()
+        .enumerate()
+        .combinations(2)
+        .map(|mut coords| {
+            coords.sort_by(|a, b| a.1.cmp(b.1));
+
+            let (x1, y1) = coords[0].1;
+            let (x2, y2) = coords[1].1;
+
+            let expansion_x = vertical_expansions
+                .iter()
+                .filter(|x| {
+                    if x1 < x2 {
+                        (x1..x2).contains(x)
+                    } else {
+                        (x2..x1).contains(x)
+                    }
+                })
+                .count();
+
+            let expansion_y = horizontal_expansions
+                .iter()
+                .filter(|y| {
+                    if y1 < y2 {
+                        (y1..y2).contains(y)
+                    } else {
+                        (y2..y1).contains(y)
+                    }
+                })
+                .count();
+
+            x1.abs_diff(*x2) + y1.abs_diff(*y2) + (expansion_x + expansion_y) * expansion_factor
+        })
+        .sum()
+}
+
+/// Sum of the distances once the universe expanded a little.
+pub fn part1(image: &Image, params: &Params) -> usize {
+    calculate_sum(
+        &image.galaxies,
+        &image.vertical_expansions,
+        &image.horizontal_expansions,
+        params.expansion_factor,
+    )
+}
+
+/// Sum of the distances once the universe expanded a lot.
+pub fn part2(image: &Image, params: &Params) -> usize {
+    calculate_sum(
+        &image.galaxies,
+        &image.vertical_expansions,
+        &image.horizontal_expansions,
+        params.full_expansion_factor,
+    )
+}
@@ -0,0 +1,154 @@
+//! Day 13: Point of Incidence.
+
+use std::str::FromStr;
+
+/// One pattern of ash and rocks.
+#[derive(Debug)]
+pub struct Pattern {
+    pub rows: Vec<String>,
+}
+
+impl Pattern {
+    /// The pattern made of `lines`, the first of which is line `first` of
+    /// the notes. Rows are read as bits, so a pattern spans 32 cells at most
+    /// either way.
+    fn parse(lines: &[&str], first: usize) -> Result<Self, String> {
+        let width = lines[0].len();
+        if width > 32 || lines.len() > 32 {
+            return Err(format!("Line {first}: pattern larger than 32 by 32"));
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(c) = line.chars().find(|&c| c != '.' && c != '#') {
+                return Err(format!("Line {}: unexpected character {c:?}", first + i));
+            }
+            if line.len() != width {
+                return Err(format!(
+                    "Line {}: expected {width} cells, got {}",
+                    first + i,
+                    line.len()
+                ));
+            }
+        }
+
+        Ok(Pattern {
+            rows: lines.iter().map(|l| l.to_string()).collect(),
+        })
+    }
+}
+
+impl Pattern {
+    fn horizontal_numbers(&self) -> Vec<u32> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .enumerate()
+                    .filter_map(|(i, c)| if c == '#' { Some(1 << i) } else { None })
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn vertical_numbers(&self) -> Vec<u32> {
+        (0..self.rows[0].len())
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .enumerate()
+                    .map(|(j, row)| (j, row.chars().nth(i)))
+                    .filter_map(|(j, c)| if c == Some('#') { Some(1 << j) } else { None })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Summary of the pattern for a perfect mirror and for a smudged one.
+    pub fn summarize(&self) -> (usize, usize) {
+        let (v1, v2) = find_reflection(&self.vertical_numbers());
+        let (h1, h2) = find_reflection(&self.horizontal_numbers());
+
+        (v1 + h1 * 100, v2 + h2 * 100)
+    }
+}
+
+#[inline]
+fn bit_diff(a: u32, b: u32) -> u32 {
+    (a ^ b).count_ones()
+}
+
+fn find_reflection(values: &[u32]) -> (usize, usize) {
+    values
+        .windows(2)
+        .enumerate()
+        .filter_map(|(i, w)| {
+            if bit_diff(w[0], w[1]) <= 1 {
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .fold((0, 0), |acc, pos| {
+            let mut min = pos;
+            let mut max = pos + 1;
+            let mut diff = 0;
+
+            loop {
+                diff += bit_diff(values[min], values[max]);
+
+                if diff > 1 {
+                    return acc;
+                }
+
+                if min > 0 && max < values.len() - 1 {
+                    min -= 1;
+                    max += 1;
+                } else {
+                    break;
+                }
+            }
+
+            match diff {
+                0 => (acc.0 + pos + 1, acc.1),
+                1 => (acc.0, acc.1 + pos + 1),
+                _ => acc,
+            }
+        })
+}
+
+/// Every pattern of the notes.
+#[derive(Debug)]
+pub struct Patterns {
+    pub patterns: Vec<Pattern>,
+}
+
+impl FromStr for Patterns {
+    type Err = String;
+
+    /// Patterns are separated by blank lines.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut patterns = vec![];
+        let mut lines = vec![];
+
+        for (i, line) in s.lines().chain([""]).enumerate() {
+            if !line.is_empty() {
+                lines.push(line);
+            } else if !lines.is_empty() {
+                patterns.push(Pattern::parse(&lines, i + 1 - lines.len())?);
+                lines.clear();
+            }
+        }
+
+        Ok(Patterns { patterns })
+    }
+}
+
+/// Summary of the notes with perfect reflections.
+pub fn part1(patterns: &Patterns) -> usize {
+    patterns.patterns.iter().map(|p| p.summarize().0).sum()
+}
+
+/// Summary of the notes once each mirror's smudge is cleaned.
+pub fn part2(patterns: &Patterns) -> usize {
+    patterns.patterns.iter().map(|p| p.summarize().1).sum()
+}
@@ -0,0 +1,157 @@
+//! Day 14: Parabolic Reflector Dish.
+
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Place {
+    Rock,
+    Block,
+    Nothing,
+}
+
+impl TryFrom<char> for Place {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        use Place::*;
+        match c {
+            'O' => Ok(Rock),
+            '#' => Ok(Block),
+            '.' => Ok(Nothing),
+            _ => Err(format!("unexpected character {c:?}")),
+        }
+    }
+}
+
+/// The platform, remembering which arrangements the spin cycle went through.
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub rows: Vec<Vec<Place>>,
+    cache: HashMap<Vec<Vec<Place>>, usize>,
+}
+
+impl FromStr for Map {
+    type Err = String;
+
+    /// One row per line, all of them as wide.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Vec<Place>> = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l.chars()
+                    .map(Place::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| format!("Line {}: {e}", i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(i) = rows.iter().position(|r| r.len() != width) {
+            return Err(format!(
+                "Line {}: expected {width} places, got {}",
+                i + 1,
+                rows[i].len()
+            ));
+        }
+
+        Ok(Map {
+            rows,
+            cache: HashMap::new(),
+        })
+    }
+}
+
+impl Map {
+    pub fn tilt(&mut self) {
+        for i in 1..self.rows.len() {
+            for j in 0..self.rows[i].len() {
+                if self.rows[i][j] == Place::Rock {
+                    for dst in (0..i).rev() {
+                        if self.rows[dst][j] != Place::Nothing {
+                            if dst != i - 1 {
+                                self.rows[dst + 1][j] = Place::Rock;
+                                self.rows[i][j] = Place::Nothing;
+                            }
+                            break;
+                        } else if dst == 0 {
+                            self.rows[0][j] = Place::Rock;
+                            self.rows[i][j] = Place::Nothing;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn rotate(&mut self) {
+        let length = self.rows.len();
+
+        let mut i = 0;
+
+        while i < length {
+            let mut j = i;
+
+            while j < length {
+                let tmp = self.rows[i][j];
+                self.rows[i][j] = self.rows[j][i];
+                self.rows[j][i] = tmp;
+
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        self.rows.iter_mut().for_each(|row| row.reverse());
+    }
+
+    pub fn spin_cycle(&mut self, count: usize) {
+        let mut i = 0;
+        while i < count {
+            self.tilt(); // tilt north
+
+            self.rotate();
+            self.tilt(); // tilt west
+
+            self.rotate();
+            self.tilt(); // tilt south
+
+            self.rotate();
+            self.tilt(); // tilt east
+
+            self.rotate(); // original orientation (north)
+
+            if let Some(n) = self.cache.get(&self.rows) {
+                let jump = (count - i) % (i - n);
+                i = count - jump;
+            }
+
+            self.cache.insert(self.rows.to_vec(), i);
+
+            i += 1;
+        }
+    }
+
+    pub fn weight(&self) -> usize {
+        self.rows
+            .iter()
+            .rev()
+            .enumerate()
+            .rev()
+            .map(|(i, row)| row.iter().filter(|p| **p == Place::Rock).count() * (i + 1))
+            .sum()
+    }
+}
+
+/// Load on the north beams, as the platform was scanned.
+pub fn part1(map: &Map) -> usize {
+    map.weight()
+}
+
+/// Load on the north beams after a billion spin cycles.
+pub fn part2(map: &Map) -> usize {
+    let mut map = map.clone();
+    map.spin_cycle(1_000_000_000);
+    map.weight()
+}
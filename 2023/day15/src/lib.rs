@@ -0,0 +1,134 @@
+//! Day 15: Lens Library.
+
+use std::str::FromStr;
+
+/// The Holiday ASCII String Helper algorithm.
+pub fn hash(s: &str) -> u8 {
+    s.chars()
+        .map(|c| c as u32)
+        .fold(0u32, |acc, x| ((acc + x) * 17) % 256) as u8
+}
+
+/// One step of the initialization sequence, with the box it targets.
+#[derive(Debug)]
+pub enum Step<'a> {
+    Remove(&'a str, u8),
+    Add(&'a str, u8, u8),
+}
+
+impl<'a> TryFrom<&'a str> for Step<'a> {
+    type Error = String;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if let Some(label) = s.strip_suffix('-') {
+            Ok(Self::Remove(label, hash(label)))
+        } else if let Some((label, focal_length)) = s.split_once('=') {
+            let focal_length = focal_length
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid focal length in step {s:?}"))?;
+            Ok(Self::Add(label, hash(label), focal_length))
+        } else {
+            Err(format!("Expected label- or label=N, got step {s:?}"))
+        }
+    }
+}
+
+impl<'a> Step<'a> {
+    pub fn run(&'a self, boxes: &mut [Vec<Lens<'a>>]) {
+        use Step::*;
+
+        match self {
+            Remove(label, target_box) => {
+                if let Some(pos) = boxes[*target_box as usize]
+                    .iter()
+                    .position(|x| &x.label == label)
+                {
+                    boxes[*target_box as usize].remove(pos);
+                }
+            }
+            Add(label, target_box, focal_length) => {
+                if let Some(pos) = boxes[*target_box as usize]
+                    .iter()
+                    .position(|x| &x.label == label)
+                {
+                    boxes[*target_box as usize][pos].focal_length = *focal_length;
+                } else {
+                    boxes[*target_box as usize].push(Lens {
+                        label,
+                        focal_length: *focal_length,
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Lens<'a> {
+    pub label: &'a str,
+    pub focal_length: u8,
+}
+
+/// The steps of the initialization sequence, as written.
+#[derive(Debug)]
+pub struct Steps {
+    pub steps: Vec<String>,
+}
+
+impl FromStr for Steps {
+    type Err = String;
+
+    /// Steps are separated by commas; line breaks are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps: Vec<String> = s
+            .lines()
+            .flat_map(|l| l.split(','))
+            .map(String::from)
+            .collect();
+
+        if let Some(e) = steps.iter().find_map(|s| Step::try_from(s.as_str()).err()) {
+            return Err(e);
+        }
+
+        Ok(Steps { steps })
+    }
+}
+
+/// Sum of the hashes of every step.
+pub fn part1(steps: &Steps) -> usize {
+    steps.steps.iter().map(|s| hash(s) as usize).sum()
+}
+
+/// Focusing power of the lenses once every step ran. Steps that aren't
+/// valid, which parsing rules out, are skipped.
+pub fn part2(steps: &Steps) -> usize {
+    let steps: Vec<_> = steps
+        .steps
+        .iter()
+        .filter_map(|s| Step::try_from(s.as_str()).ok())
+        .collect();
+
+    let mut boxes = vec![vec![]; 256];
+    steps.iter().for_each(|s| s.run(&mut boxes));
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            b.iter()
+                .enumerate()
+                .map(|(j, l)| (i + 1) * (j + 1) * l.focal_length as usize)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_works() {
+        assert_eq!(hash("HASH"), 52);
+    }
+}
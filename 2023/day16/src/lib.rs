@@ -0,0 +1,226 @@
+//! Day 16: The Floor Will Be Lava.
+
+use std::str::FromStr;
+
+/// Direction the beam travels in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    pub content: Content,
+    pub energized_from: Vec<Direction>,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: Content::try_from(c)?,
+            energized_from: vec![],
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Content {
+    Empty,
+    MirrorSWNE,
+    MirrorNWSE,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+
+impl TryFrom<char> for Content {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        use Content::*;
+
+        match c {
+            '/' => Ok(MirrorSWNE),
+            '\\' => Ok(MirrorNWSE),
+            '-' => Ok(SplitterHorizontal),
+            '|' => Ok(SplitterVertical),
+            '.' => Ok(Empty),
+            _ => Err(format!("unexpected tile {c:?}")),
+        }
+    }
+}
+
+/// The contraption, with the directions each tile was energized from.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    pub rows: Vec<Vec<Tile>>,
+}
+
+impl FromStr for Layout {
+    type Err = String;
+
+    /// One row per line, all of them as wide.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Vec<Tile>> = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l.chars()
+                    .map(Tile::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| format!("Line {}: {e}", i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err("The layout is empty".into());
+        }
+        if let Some(i) = rows.iter().position(|r| r.len() != width) {
+            return Err(format!(
+                "Line {}: expected {width} tiles, got {}",
+                i + 1,
+                rows[i].len()
+            ));
+        }
+
+        Ok(Layout { rows })
+    }
+}
+
+impl Layout {
+    pub fn count_energized(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter(|tile| !tile.energized_from.is_empty())
+                    .count()
+            })
+            .sum()
+    }
+
+    pub fn reset_energized(&mut self) {
+        self.rows
+            .iter_mut()
+            .for_each(|row| row.iter_mut().for_each(|tile| tile.energized_from.clear()));
+    }
+
+    pub fn entrypoints(&self) -> Vec<((usize, usize), Direction)> {
+        use Direction::*;
+        let mut v = vec![];
+
+        for i in 0..self.rows.len() {
+            v.push(((0, i), Right));
+            v.push(((self.rows[i].len() - 1, i), Left));
+        }
+
+        for i in 0..self.rows[0].len() {
+            v.push(((i, 0), Down));
+            v.push(((i, self.rows.len() - 1), Up));
+        }
+
+        v
+    }
+
+    pub fn traverse(&mut self, entrypoint: ((usize, usize), Direction)) {
+        let mut stack = vec![entrypoint];
+
+        while let Some(((x, y), direction)) = stack.pop() {
+            if self.rows[y][x].energized_from.contains(&direction) {
+                continue;
+            } else {
+                self.rows[y][x].energized_from.push(direction);
+            }
+
+            use Content::*;
+            use Direction::*;
+
+            match (self.rows[y][x].content, direction) {
+                (Empty | SplitterHorizontal, Right) => {
+                    if x < (self.rows[y].len() - 1) {
+                        stack.push(((x + 1, y), Right))
+                    }
+                }
+                (Empty | SplitterHorizontal, Left) => {
+                    if x > 0 {
+                        stack.push(((x - 1, y), Left))
+                    }
+                }
+                (Empty | SplitterVertical, Up) => {
+                    if y > 0 {
+                        stack.push(((x, y - 1), Up))
+                    }
+                }
+                (Empty | SplitterVertical, Down) => {
+                    if y < (self.rows.len() - 1) {
+                        stack.push(((x, y + 1), Down))
+                    }
+                }
+                (SplitterVertical, Right | Left) => {
+                    if y > 0 {
+                        stack.push(((x, y - 1), Up))
+                    }
+                    if y < (self.rows.len() - 1) {
+                        stack.push(((x, y + 1), Down))
+                    }
+                }
+                (SplitterHorizontal, Up | Down) => {
+                    if x > 0 {
+                        stack.push(((x - 1, y), Left))
+                    }
+                    if x < (self.rows[y].len() - 1) {
+                        stack.push(((x + 1, y), Right))
+                    }
+                }
+                (MirrorSWNE, Right) | (MirrorNWSE, Left) => {
+                    if y > 0 {
+                        stack.push(((x, y - 1), Up))
+                    }
+                }
+                (MirrorSWNE, Down) | (MirrorNWSE, Up) => {
+                    if x > 0 {
+                        stack.push(((x - 1, y), Left))
+                    }
+                }
+                (MirrorSWNE, Up) | (MirrorNWSE, Down) => {
+                    if x < (self.rows[y].len() - 1) {
+                        stack.push(((x + 1, y), Right))
+                    }
+                }
+                (MirrorSWNE, Left) | (MirrorNWSE, Right) => {
+                    if y < (self.rows.len() - 1) {
+                        stack.push(((x, y + 1), Down))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Tiles energized by a beam entering the top-left corner heading right.
+pub fn part1(layout: &Layout) -> usize {
+    let mut layout = layout.clone();
+    layout.traverse(((0, 0), Direction::Right));
+    layout.count_energized()
+}
+
+/// Most tiles energized by a beam entering from any edge.
+pub fn part2(layout: &Layout) -> usize {
+    let mut layout = layout.clone();
+
+    layout
+        .entrypoints()
+        .into_iter()
+        .map(|e| {
+            layout.reset_energized();
+            layout.traverse(e);
+            layout.count_energized()
+        })
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static BASELINE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// System allocator wrapper keeping track of live, peak and total bytes.
+///
+/// It is installed as the global allocator when the `alloc-stats` feature is
+/// enabled, so the days opt in by forwarding that feature to this crate.
+pub struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn grow(size: usize) {
+    COUNT.fetch_add(1, Relaxed);
+    TOTAL.fetch_add(size, Relaxed);
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+            grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub peak_bytes: usize,
+    pub allocations: usize,
+    pub total_bytes: usize,
+}
+
+pub fn reset() {
+    let current = CURRENT.load(Relaxed);
+    BASELINE.store(current, Relaxed);
+    PEAK.store(current, Relaxed);
+    COUNT.store(0, Relaxed);
+    TOTAL.store(0, Relaxed);
+}
+
+/// Counters since the last `reset`, with the peak measured above the bytes
+/// that were already live at that point.
+pub fn stats() -> Option<Stats> {
+    if !ENABLED {
+        return None;
+    }
+
+    Some(Stats {
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(BASELINE.load(Relaxed)),
+        allocations: COUNT.load(Relaxed),
+        total_bytes: TOTAL.load(Relaxed),
+    })
+}
+
+pub fn human(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
@@ -0,0 +1,93 @@
+use serde::Serialize;
+use std::fmt::Display;
+
+/// A puzzle answer, for tools that handle every day the same way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum Answer {
+    Integer(i64),
+    /// Unsigned answers too large for `Integer`.
+    Unsigned(u64),
+    Text(String),
+    Image(Image),
+}
+
+/// A picture drawn by a puzzle, such as the letters on a CRT screen.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct Image {
+    pub rows: Vec<Vec<bool>>,
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            let line: String = row.iter().map(|b| if *b { '#' } else { '.' }).collect();
+            f.write_str(&line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{i}"),
+            Answer::Unsigned(u) => write!(f, "{u}"),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Image(image) => write!(f, "{image}"),
+        }
+    }
+}
+
+macro_rules! lossless {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+lossless!(i8, i16, i32, i64, u8, u16, u32);
+
+// Pointer-sized integers are at most 64 bits wide on every target Rust
+// supports, so these casts never truncate.
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Image> for Answer {
+    fn from(value: Image) -> Self {
+        Answer::Image(value)
+    }
+}
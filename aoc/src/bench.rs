@@ -0,0 +1,136 @@
+use aoc::{
+    alloc,
+    catalogue::{Puzzle, PUZZLES},
+    runner::{Record, REPORT_VAR},
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+fn pick_input(dir: &Path, puzzle: &Puzzle, input: Option<&str>) -> Option<PathBuf> {
+    let candidates = match input {
+        Some(name) => vec![name],
+        None => std::iter::once("input.txt")
+            .chain(puzzle.samples.iter().map(|s| s.file))
+            .collect(),
+    };
+
+    candidates
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn run(dir: &Path, input: &Path, alloc: bool) -> Result<Vec<Record>, String> {
+    let report = env::temp_dir().join(format!("aoc-bench-{}.jsonl", std::process::id()));
+    let _ = fs::remove_file(&report);
+
+    let mut command = Command::new("cargo");
+    command.args(["run", "--quiet", "--release"]);
+    if alloc {
+        command.args(["--features", "alloc-stats"]);
+    }
+
+    let output = command
+        .current_dir(dir)
+        .env(REPORT_VAR, &report)
+        .stdin(fs::File::open(input).map_err(|e| e.to_string())?)
+        .stdout(Stdio::null())
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.lines().last().unwrap_or("failed").to_string());
+    }
+
+    let records = fs::read_to_string(&report)
+        .unwrap_or_default()
+        .lines()
+        .map(|l| serde_json::from_str(l).map_err(|e| e.to_string()))
+        .collect();
+    let _ = fs::remove_file(&report);
+
+    records
+}
+
+pub fn run_all(args: &[String]) {
+    let mut year = None;
+    let mut day = None;
+    let mut input = None;
+    let mut alloc = false;
+    let mut json = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = args.next().and_then(|y| y.parse::<u16>().ok()),
+            "--day" => day = args.next().and_then(|d| d.parse::<u8>().ok()),
+            "--input" => input = args.next().cloned(),
+            "--alloc" => alloc = true,
+            "--json" => json = true,
+            _ => super::usage(),
+        }
+    }
+
+    let root = super::root();
+    let mut records = vec![];
+
+    for puzzle in PUZZLES
+        .iter()
+        .filter(|p| year.is_none_or(|y| p.year == y))
+        .filter(|p| day.is_none_or(|d| p.day == d))
+    {
+        let dir = root.join(puzzle.path());
+        if !dir.is_dir() {
+            continue;
+        }
+
+        let Some(input) = pick_input(&dir, puzzle, input.as_deref()) else {
+            eprintln!("{}: no input file", puzzle.path());
+            continue;
+        };
+
+        match run(&dir, &input, alloc) {
+            Ok(r) if r.is_empty() => eprintln!("{}: does not use the runner", puzzle.path()),
+            Ok(r) => records.extend(r),
+            Err(e) => eprintln!("{}: {}", puzzle.path(), e),
+        }
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("Couldn't serialize report")
+        );
+        return;
+    }
+
+    println!(
+        "{:<12} {:>4} {:>14} {:>12} {:>12} {:>12}",
+        "Puzzle", "Part", "Time", "Peak", "Allocations", "Allocated"
+    );
+    for r in &records {
+        let (peak, count, total) = match r.alloc {
+            Some(s) => (
+                alloc::human(s.peak_bytes),
+                s.allocations.to_string(),
+                alloc::human(s.total_bytes),
+            ),
+            None => ("-".into(), "-".into(), "-".into()),
+        };
+
+        println!(
+            "{:<12} {:>4} {:>14} {:>12} {:>12} {:>12}",
+            format!("{}/day{:02}", r.year, r.day),
+            r.part,
+            format!("{:.2?}", Duration::from_nanos(r.elapsed_ns as u64)),
+            peak,
+            count,
+            total
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use aoc::catalogue;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+enum Outcome {
+    Good,
+    Changed(String),
+    Failed(String),
+}
+
+struct Worktree {
+    repo: PathBuf,
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(repo: &Path) -> Result<Self, String> {
+        let path = env::temp_dir().join(format!("aoc-bisect-{}", process::id()));
+
+        git(repo, &["worktree", "add", "--detach", "--quiet"], &[&path])?;
+
+        Ok(Self {
+            repo: repo.to_path_buf(),
+            path,
+        })
+    }
+
+    fn checkout(&self, rev: &str) -> Result<(), String> {
+        git(&self.path, &["checkout", "--detach", "--quiet", rev], &[]).map(drop)
+    }
+
+    fn target_dir(&self) -> PathBuf {
+        self.path.with_extension("target")
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = Command::new("git")
+            .current_dir(&self.repo)
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .status();
+        let _ = fs::remove_dir_all(self.target_dir());
+    }
+}
+
+fn git(dir: &Path, args: &[&str], paths: &[&Path]) -> Result<String, String> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .args(paths)
+        .output()
+        .map_err(|e| format!("Couldn't run git: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn answers(stdout: &str) -> Vec<&str> {
+    stdout
+        .lines()
+        .map(|l| l.rsplit(": ").next().unwrap_or(l).trim())
+        .collect()
+}
+
+fn probe(worktree: &Worktree, day_path: &str, input: &Path, answer: &str) -> Outcome {
+    let dir = worktree.path.join(day_path);
+    if !dir.is_dir() {
+        return Outcome::Failed(format!("{day_path} does not exist"));
+    }
+
+    let stdin = match fs::File::open(input) {
+        Ok(stdin) => stdin,
+        Err(e) => return Outcome::Failed(format!("Couldn't open input file: {e}")),
+    };
+    let output = match Command::new("cargo")
+        .args(["run", "--quiet", "--release"])
+        .current_dir(&dir)
+        .env("CARGO_TARGET_DIR", worktree.target_dir())
+        .stdin(stdin)
+        .stderr(Stdio::piped())
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => return Outcome::Failed(format!("Couldn't run cargo: {e}")),
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Outcome::Failed(stderr.lines().last().unwrap_or("failed").to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answers = answers(&stdout);
+
+    if answers.contains(&answer) {
+        Outcome::Good
+    } else {
+        Outcome::Changed(answers.join(", "))
+    }
+}
+
+/// Probes `rev` and prints how it went. A revision that fails to build or
+/// run says nothing about the answer, so the caller skips it.
+fn check(
+    worktree: &Worktree,
+    rev: &str,
+    day_path: &str,
+    input: &Path,
+    answer: &str,
+) -> Result<Outcome, String> {
+    worktree.checkout(rev)?;
+
+    let outcome = probe(worktree, day_path, input, answer);
+    let subject = git(&worktree.path, &["log", "-1", "--format=%h %s"], &[])?;
+
+    match &outcome {
+        Outcome::Good => println!("good     {}", subject.trim()),
+        Outcome::Changed(got) => println!("changed  {} (got {})", subject.trim(), got),
+        Outcome::Failed(err) => println!("skipped  {} ({})", subject.trim(), err),
+    }
+
+    Ok(outcome)
+}
+
+/// The revision strictly between `lo` and `hi` closest to their middle that
+/// wasn't skipped yet.
+fn next_probe(lo: usize, hi: usize, skipped: &[bool]) -> Option<usize> {
+    let mid = (lo + hi) / 2;
+
+    (lo + 1..hi)
+        .filter(|&i| !skipped[i])
+        .min_by_key(|&i| i.abs_diff(mid))
+}
+
+pub fn run(args: &[String]) {
+    let (Some(year), Some(day)) = (
+        args.first().and_then(|y| y.parse::<u16>().ok()),
+        args.get(1).and_then(|d| d.parse::<u8>().ok()),
+    ) else {
+        super::usage()
+    };
+
+    let mut answer = None;
+    let mut input = None;
+    let mut good = None;
+    let mut bad = String::from("HEAD");
+
+    let mut args = args[2..].iter();
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--answer", Some(a)) => answer = Some(a.clone()),
+            ("--input", Some(i)) => input = Some(i.clone()),
+            ("--good", Some(g)) => good = Some(g.clone()),
+            ("--bad", Some(b)) => bad = b.clone(),
+            _ => super::usage(),
+        }
+    }
+
+    let (Some(answer), Some(input)) = (answer, input) else {
+        super::usage()
+    };
+    let input = fs::canonicalize(&input).expect("Couldn't find input file");
+
+    let day_path = catalogue::find(year, day)
+        .map(|p| p.path())
+        .unwrap_or_else(|| format!("{year}/day{day:02}"));
+
+    // Errors are reported once `bisect` returned, so that the worktree it
+    // made is removed first.
+    if let Err(e) = bisect(&day_path, &input, &answer, good.as_deref(), &bad) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn bisect(
+    day_path: &str,
+    input: &Path,
+    answer: &str,
+    good: Option<&str>,
+    bad: &str,
+) -> Result<(), String> {
+    let root = super::root();
+    let range = match good {
+        Some(good) => format!("{good}..{bad}"),
+        None => bad.to_string(),
+    };
+    let mut revisions: Vec<String> = good.iter().map(|g| g.to_string()).collect();
+    revisions.extend(
+        git(
+            &root,
+            &[
+                "rev-list",
+                "--reverse",
+                "--first-parent",
+                &range,
+                "--",
+                day_path,
+                "aoc",
+            ],
+            &[],
+        )?
+        .lines()
+        .map(String::from),
+    );
+
+    if revisions.is_empty() {
+        return Err(format!("No revisions touch {day_path}"));
+    }
+
+    let worktree = Worktree::add(&root)?;
+
+    let (mut lo, mut hi) = (0, revisions.len() - 1);
+    match check(&worktree, &revisions[lo], day_path, input, answer)? {
+        Outcome::Good => {}
+        Outcome::Changed(_) => {
+            println!("\nThe oldest revision does not produce {answer}; pass an older --good");
+            return Ok(());
+        }
+        Outcome::Failed(_) => {
+            println!("\nThe oldest revision fails to build or run; pass another --good");
+            return Ok(());
+        }
+    }
+    match check(&worktree, &revisions[hi], day_path, input, answer)? {
+        Outcome::Good => {
+            println!("\nThe answer {answer} is unchanged up to {bad}");
+            return Ok(());
+        }
+        Outcome::Changed(_) => {}
+        Outcome::Failed(_) => {
+            println!("\nThe newest revision fails to build or run; pass another --bad");
+            return Ok(());
+        }
+    }
+
+    let mut skipped = vec![false; revisions.len()];
+    while let Some(mid) = next_probe(lo, hi, &skipped) {
+        match check(&worktree, &revisions[mid], day_path, input, answer)? {
+            Outcome::Good => lo = mid,
+            Outcome::Changed(_) => hi = mid,
+            Outcome::Failed(_) => skipped[mid] = true,
+        }
+    }
+
+    let log = |rev: &str| git(&root, &["log", "-1", "--format=%H%n%an, %ad%n%s", rev], &[]);
+    let untested: Vec<&String> = (lo + 1..hi).map(|i| &revisions[i]).collect();
+    if untested.is_empty() {
+        println!(
+            "\nFirst revision where the answer changed:\n{}",
+            log(&revisions[hi])?
+        );
+    } else {
+        println!("\nThe answer changed in one of these revisions; all but the last were skipped:");
+        for rev in untested.into_iter().chain([&revisions[hi]]) {
+            println!("\n{}", log(rev)?.trim_end());
+        }
+    }
+
+    Ok(())
+}
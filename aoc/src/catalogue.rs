@@ -0,0 +1,328 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct Sample {
+    pub file: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub solved: &'static [u8],
+    pub samples: &'static [Sample],
+    pub caveats: &'static [&'static str],
+}
+
+impl Puzzle {
+    pub fn path(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    pub fn stars(&self) -> usize {
+        self.solved.len()
+    }
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.year == year && p.day == day)
+}
+
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = PUZZLES.iter().map(|p| p.year).collect();
+    years.dedup();
+    years
+}
+
+const fn sample(file: &'static str, part1: &'static str, part2: &'static str) -> Sample {
+    Sample {
+        file,
+        part1: Some(part1),
+        part2: Some(part2),
+    }
+}
+
+const fn sample_part1(file: &'static str, part1: &'static str) -> Sample {
+    Sample {
+        file,
+        part1: Some(part1),
+        part2: None,
+    }
+}
+
+const fn sample_part2(file: &'static str, part2: &'static str) -> Sample {
+    Sample {
+        file,
+        part1: None,
+        part2: Some(part2),
+    }
+}
+
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        year: 2022,
+        day: 1,
+        title: "Calorie Counting",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "24000", "45000")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 2,
+        title: "Rock Paper Scissors",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "15", "12")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 3,
+        title: "Rucksack Reorganization",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "157", "70")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 4,
+        title: "Camp Cleanup",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "2", "4")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 5,
+        title: "Supply Stacks",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "CMZ", "MCD")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 6,
+        title: "Tuning Trouble",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "11", "26")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 7,
+        title: "No Space Left On Device",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "95437", "24933642")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 8,
+        title: "Treetop Tree House",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "21", "8")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 9,
+        title: "Rope Bridge",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "13", "1")],
+        caveats: &["The larger part 2 example is not checked in"],
+    },
+    Puzzle {
+        year: 2022,
+        day: 10,
+        title: "Cathode-Ray Tube",
+        solved: &[1, 2],
+        samples: &[sample_part1("input_small.txt", "13140")],
+        caveats: &["The example draws no letters, so part 2 falls back to the CRT image"],
+    },
+    Puzzle {
+        year: 2022,
+        day: 11,
+        title: "Monkey in the Middle",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "10605", "2713310158")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 12,
+        title: "Hill Climbing Algorithm",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "31", "29")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 13,
+        title: "Distress Signal",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "13", "140")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 14,
+        title: "Regolith Reservoir",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "24", "93")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2022,
+        day: 15,
+        title: "Beacon Exclusion Zone",
+        solved: &[1],
+        samples: &[sample_part1("input_small.txt", "26")],
+        caveats: &[
+            "Only part 1 is answered",
+            "Part 1 materializes every covered cell, which does not scale to the real input",
+        ],
+    },
+    Puzzle {
+        year: 2023,
+        day: 1,
+        title: "Trebuchet?!",
+        solved: &[1, 2],
+        samples: &[
+            sample_part1("input_small.txt", "142"),
+            sample_part2("input_small_step2.txt", "281"),
+        ],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 2,
+        title: "Cube Conundrum",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "8", "2286")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 3,
+        title: "Gear Ratios",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "4361", "467835")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 4,
+        title: "Scratchcards",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "13", "30")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 5,
+        title: "If You Give A Seed A Fertilizer",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "35", "46")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 6,
+        title: "Wait For It",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "288", "71503")],
+        caveats: &["Falls back to built-in race tables when stdin holds no races"],
+    },
+    Puzzle {
+        year: 2023,
+        day: 7,
+        title: "Camel Cards",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "6440", "5905")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 8,
+        title: "Haunted Wasteland",
+        solved: &[1, 2],
+        samples: &[
+            sample_part1("input_small.txt", "2"),
+            sample_part1("input_small2.txt", "6"),
+        ],
+        caveats: &["The part 2 example with ghost nodes is not checked in"],
+    },
+    Puzzle {
+        year: 2023,
+        day: 9,
+        title: "Mirage Maintenance",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "114", "2")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 10,
+        title: "Pipe Maze",
+        solved: &[1, 2],
+        samples: &[
+            sample_part1("input_simple.txt", "4"),
+            sample_part1("input_small.txt", "8"),
+            sample_part2("input_small_step2.txt", "4"),
+            sample_part2("input_small_step2b.txt", "8"),
+            sample_part2("input_small_step2c.txt", "10"),
+        ],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 11,
+        title: "Cosmic Expansion",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "374", "8410")],
+        caveats: &["The example profile expands empty space 100 times for part 2"],
+    },
+    Puzzle {
+        year: 2023,
+        day: 12,
+        title: "Hot Springs",
+        solved: &[],
+        samples: &[],
+        caveats: &["Not attempted; there is no crate for this day"],
+    },
+    Puzzle {
+        year: 2023,
+        day: 13,
+        title: "Point of Incidence",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "405", "400")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 14,
+        title: "Parabolic Reflector Dish",
+        solved: &[2],
+        samples: &[sample_part2("input_small.txt", "64")],
+        caveats: &["The first printed weight is taken before tilting, so part 1 is not answered"],
+    },
+    Puzzle {
+        year: 2023,
+        day: 15,
+        title: "Lens Library",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "1320", "145")],
+        caveats: &[],
+    },
+    Puzzle {
+        year: 2023,
+        day: 16,
+        title: "The Floor Will Be Lava",
+        solved: &[1, 2],
+        samples: &[sample("input_small.txt", "46", "51")],
+        caveats: &[],
+    },
+];
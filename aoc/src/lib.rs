@@ -0,0 +1,19 @@
+pub mod alloc;
+pub mod answer;
+pub mod catalogue;
+pub mod profile;
+pub mod runner;
+
+pub use answer::{Answer, Image};
+
+use std::io::{self, Read};
+
+pub fn read_stdin() -> String {
+    let mut input = String::new();
+    io::stdin()
+        .lock()
+        .read_to_string(&mut input)
+        .expect("Couldn't read stdin");
+
+    input
+}
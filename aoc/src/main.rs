@@ -0,0 +1,36 @@
+mod bench;
+mod bisect;
+mod status;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+};
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate has no parent directory")
+        .to_path_buf()
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc status [--json] [--year <year>]");
+    eprintln!("       aoc bench [--year <year>] [--day <day>] [--input <file>] [--alloc] [--json]");
+    eprintln!(
+        "       aoc bisect <year> <day> --answer <answer> --input <file> [--good <rev>] [--bad <rev>]"
+    );
+    process::exit(2);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("status") => status::run(&args[1..]),
+        Some("bench") => bench::run_all(&args[1..]),
+        Some("bisect") => bisect::run(&args[1..]),
+        _ => usage(),
+    }
+}
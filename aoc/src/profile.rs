@@ -0,0 +1,178 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+const HEADER_PREFIX: &str = "# profile:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Example,
+    Real,
+}
+
+impl FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "example" => Ok(Profile::Example),
+            "real" => Ok(Profile::Real),
+            other => Err(format!("Unknown input profile: {other}")),
+        }
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Profile::Example => "example",
+            Profile::Real => "real",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Header,
+    Sidecar(PathBuf),
+    Fingerprint,
+    Default,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Header => f.write_str("header comment"),
+            Source::Sidecar(path) => write!(f, "sidecar file {}", path.display()),
+            Source::Fingerprint => f.write_str("input fingerprint"),
+            Source::Default => f.write_str("default"),
+        }
+    }
+}
+
+/// Puzzle input along with the profile it was detected as.
+///
+/// Explicit metadata wins over heuristics: a `# profile: <example|real>` first
+/// line, then a `<input>.profile` file next to the input redirected into stdin,
+/// then the day's fingerprint function. Inputs nobody claims are `Real`. A
+/// header or sidecar with an unknown profile is warned about and skipped.
+#[derive(Debug)]
+pub struct Input {
+    pub text: String,
+    pub profile: Profile,
+    pub source: Source,
+}
+
+impl Input {
+    pub fn from_stdin(fingerprint: fn(&str) -> Option<Profile>) -> Self {
+        Self::detect(crate::read_stdin(), stdin_path().as_deref(), fingerprint)
+    }
+
+    pub fn detect(
+        text: String,
+        path: Option<&Path>,
+        fingerprint: fn(&str) -> Option<Profile>,
+    ) -> Self {
+        let (header, text) = split_header(text);
+
+        let (profile, source) = if let Some(profile) = header {
+            (profile, Source::Header)
+        } else if let Some((profile, sidecar)) = path.and_then(read_sidecar) {
+            (profile, Source::Sidecar(sidecar))
+        } else if let Some(profile) = fingerprint(&text) {
+            (profile, Source::Fingerprint)
+        } else {
+            (Profile::Real, Source::Default)
+        };
+
+        Self {
+            text,
+            profile,
+            source,
+        }
+    }
+
+    pub fn report(&self, params: impl Display) {
+        eprintln!(
+            "Input profile: {} (from {}); {}",
+            self.profile, self.source, params
+        );
+    }
+}
+
+/// The profile named by a header line, which is stripped from `text` even when
+/// the profile is unknown.
+fn split_header(text: String) -> (Option<Profile>, String) {
+    let first = text.lines().next().unwrap_or_default();
+
+    match first.strip_prefix(HEADER_PREFIX) {
+        Some(value) => {
+            let profile = value
+                .parse()
+                .map_err(|e| eprintln!("Ignoring profile header: {e}"))
+                .ok();
+            let rest = text[first.len()..].trim_start_matches(['\r', '\n']);
+            (profile, rest.to_string())
+        }
+        None => (None, text),
+    }
+}
+
+fn read_sidecar(input: &Path) -> Option<(Profile, PathBuf)> {
+    let sidecar = input.with_extension("profile");
+    let contents = fs::read_to_string(&sidecar).ok()?;
+    let profile = contents
+        .parse()
+        .map_err(|e| eprintln!("Ignoring {}: {e}", sidecar.display()))
+        .ok()?;
+
+    Some((profile, sidecar))
+}
+
+fn stdin_path() -> Option<PathBuf> {
+    fs::read_link("/proc/self/fd/0")
+        .ok()
+        .filter(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn never(_: &str) -> Option<Profile> {
+        None
+    }
+
+    fn always_example(_: &str) -> Option<Profile> {
+        Some(Profile::Example)
+    }
+
+    #[test]
+    fn header_is_stripped_and_wins_over_fingerprint() {
+        let input = Input::detect("# profile: real\n1 2 3\n".into(), None, always_example);
+
+        assert_eq!(input.profile, Profile::Real);
+        assert_eq!(input.source, Source::Header);
+        assert_eq!(input.text, "1 2 3\n");
+    }
+
+    #[test]
+    fn unknown_header_falls_through() {
+        let input = Input::detect("# profile: huge\n1 2 3\n".into(), None, always_example);
+
+        assert_eq!(input.profile, Profile::Example);
+        assert_eq!(input.source, Source::Fingerprint);
+        assert_eq!(input.text, "1 2 3\n");
+    }
+
+    #[test]
+    fn falls_back_to_real() {
+        let input = Input::detect("1 2 3\n".into(), None, never);
+
+        assert_eq!(input.profile, Profile::Real);
+        assert_eq!(input.source, Source::Default);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    alloc::{self, Stats},
+    Answer,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    convert::Infallible,
+    env,
+    fs::OpenOptions,
+    io::Write,
+    time::{Duration, Instant},
+};
+
+pub const REPORT_VAR: &str = "AOC_REPORT";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub label: String,
+    pub elapsed_ns: u128,
+    pub alloc: Option<Stats>,
+}
+
+/// Runs each part of a day, printing its answer and measuring it.
+///
+/// Measurements go to stderr when the counting allocator is enabled, and are
+/// appended as JSON lines to the file named by `AOC_REPORT` when it is set.
+pub struct Runner {
+    year: u16,
+    day: u8,
+}
+
+impl Runner {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+
+    pub fn part<T: Clone + Into<Answer>>(&self, part: u8, label: &str, f: impl FnOnce() -> T) -> T {
+        self.try_part(part, label, || Ok::<_, Infallible>(f()))
+            .unwrap_or_else(|e| match e {})
+    }
+
+    /// Like `part`, for a part that can fail: its error is handed back to the
+    /// caller, and neither printed nor reported.
+    pub fn try_part<T: Clone + Into<Answer>, E>(
+        &self,
+        part: u8,
+        label: &str,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        alloc::reset();
+        let start = Instant::now();
+        let answer = f()?;
+        let elapsed = start.elapsed();
+        let stats = alloc::stats();
+
+        let text = answer.clone().into().to_string();
+        if text.contains('\n') {
+            println!("{label}:\n{text}");
+        } else {
+            println!("{label}: {text}");
+        }
+
+        self.report(Record {
+            year: self.year,
+            day: self.day,
+            part,
+            label: label.to_string(),
+            elapsed_ns: elapsed.as_nanos(),
+            alloc: stats,
+        });
+
+        Ok(answer)
+    }
+
+    fn report(&self, record: Record) {
+        if let Some(stats) = record.alloc {
+            eprintln!(
+                "Part {}: {:?}, peak {}, {} allocations, {} allocated",
+                record.part,
+                Duration::from_nanos(record.elapsed_ns as u64),
+                alloc::human(stats.peak_bytes),
+                stats.allocations,
+                alloc::human(stats.total_bytes)
+            );
+        }
+
+        if let Ok(path) = env::var(REPORT_VAR) {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .expect("Couldn't open report file");
+            let line = serde_json::to_string(&record).expect("Couldn't serialize record");
+            writeln!(file, "{line}").expect("Couldn't write report file");
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use aoc::catalogue::{self, Puzzle, PUZZLES};
+use std::fmt::Write;
+
+fn calendar(years: &[u16]) -> String {
+    let mut s = String::from("     ");
+    for day in 1..=25 {
+        write!(s, "{day:>3}").unwrap();
+    }
+    s.push('\n');
+
+    for year in years {
+        write!(s, "{year} ").unwrap();
+        for day in 1..=25 {
+            let cell = match catalogue::find(*year, day).map(Puzzle::stars) {
+                Some(2) => "**",
+                Some(1) => " *",
+                Some(_) => " -",
+                None => "  ",
+            };
+            write!(s, " {cell}").unwrap();
+        }
+        s.truncate(s.trim_end().len());
+        s.push('\n');
+    }
+
+    s
+}
+
+pub fn run(args: &[String]) {
+    let mut json = false;
+    let mut years = catalogue::years();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--year" => {
+                let year = args
+                    .next()
+                    .and_then(|y| y.parse::<u16>().ok())
+                    .unwrap_or_else(|| super::usage());
+                years.retain(|y| *y == year);
+            }
+            _ => super::usage(),
+        }
+    }
+
+    let puzzles: Vec<&Puzzle> = PUZZLES.iter().filter(|p| years.contains(&p.year)).collect();
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&puzzles).expect("Couldn't serialize catalogue")
+        );
+        return;
+    }
+
+    print!("{}", calendar(&years));
+    println!("\n** both parts   * one part   - not solved\n");
+
+    let stars: usize = puzzles.iter().map(|p| p.stars()).sum();
+    println!("Stars: {}/{}\n", stars, puzzles.len() * 2);
+
+    puzzles
+        .iter()
+        .filter(|p| !p.caveats.is_empty())
+        .for_each(|p| {
+            println!("{} {}:", p.path(), p.title);
+            p.caveats.iter().for_each(|c| println!("  - {c}"));
+        });
+}